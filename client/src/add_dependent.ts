@@ -0,0 +1,61 @@
+/*
+ * Example script to add a dependent to the wallet's membership.  The
+ * dependent's premium for the rest of the current paid period is charged
+ * immediately; later premiums include it automatically.  Claims for the
+ * dependent are filed by the subscriber with `submit_claim`, passing the
+ * Dependent PDA printed below.  The wallet must not be in an employer group
+ * and must have paid the current premium period itself.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda, getPremiumPoolPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  const usdcMint = new PublicKey('ReplaceWithUsdCMint');
+  // Hash of the dependent's off-chain identity record
+  const identityHash = Array(32).fill(0);
+
+  const [configPda] = getConfigPda();
+  const [premiumPoolPda] = getPremiumPoolPda();
+  const [memberPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('member'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const member = await program.account.member.fetch(memberPda);
+  const [dependentPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('dependent'), memberPda.toBuffer(), member.nextDependentId.toArrayLike(Buffer, 'le', 8)],
+    PROGRAM_ID,
+  );
+  const userUsdc = await getAssociatedTokenAddress(usdcMint, user.publicKey);
+
+  const txSig = await program.methods
+    .addDependent(identityHash)
+    .accounts({
+      config: configPda,
+      member: memberPda,
+      policy: member.policy,
+      dependent: dependentPda,
+      authority: user.publicKey,
+      userUsdcAccount: userUsdc,
+      premiumPool: premiumPoolPda,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([])
+    .rpc();
+  console.log('Dependent', dependentPda.toBase58(), 'added, tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
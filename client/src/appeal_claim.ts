@@ -0,0 +1,74 @@
+/*
+ * Example script to appeal a denied claim.  Appealing opens a governance
+ * proposal that APH stakers vote on with the `vote` script.  Once voting has
+ * closed, run this script again to resolve the appeal: if the vote passed,
+ * the claim is paid to the payee recorded on it, otherwise it returns to
+ * denied.  Resolution is permissionless, so anyone may run the second step.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda, getPremiumPoolPda, getProposalPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  // Replace with the denied claim PDA
+  const claimPda = new PublicKey('ReplaceWithClaimPda');
+  // Hash of the new off-chain evidence supporting the appeal
+  const evidenceHash = Array(32).fill(0);
+
+  const [configPda] = getConfigPda();
+  const [premiumPoolPda] = getPremiumPoolPda();
+  const claim = await program.account.claim.fetch(claimPda);
+  const memberPda = claim.member as PublicKey;
+  const member = await program.account.member.fetch(memberPda);
+
+  if (claim.status.denied) {
+    const config = await program.account.config.fetch(configPda);
+    const [proposalPda] = getProposalPda(config.nextProposalId);
+    const txSig = await program.methods
+      .appealClaim(evidenceHash)
+      .accounts({
+        config: configPda,
+        member: memberPda,
+        claim: claimPda,
+        dependent: claim.dependent,
+        proposal: proposalPda,
+        authority: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([])
+      .rpc();
+    console.log('Appeal opened as proposal', proposalPda.toBase58(), 'tx:', txSig);
+    return;
+  }
+
+  const txSig = await program.methods
+    .resolveAppeal()
+    .accounts({
+      config: configPda,
+      proposal: claim.appealProposal,
+      member: memberPda,
+      policy: member.policy,
+      dependent: claim.dependent,
+      claim: claimPda,
+      premiumPool: premiumPoolPda,
+      payeeUsdcAccount: claim.payee,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([])
+    .rpc();
+  console.log('Appeal resolution tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
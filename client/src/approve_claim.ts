@@ -1,6 +1,8 @@
 /*
  * Approves a pending claim.  Only the protocol authority should run this
- * script.  Provide the claim PDA address as a parameter.
+ * script.  Provide the claim PDA address as a parameter.  To approve less
+ * than the billed amount, set the allowed amount below the claim amount and
+ * record why with a reason code; the claim is then marked partially approved.
  */
 
 import * as anchor from '@coral-xyz/anchor';
@@ -18,22 +20,33 @@ async function main() {
 
   // Replace with the claim PDA you wish to approve
   const claimPda = new PublicKey('ReplaceWithClaimPda');
-  // Replace with the member's authority (the claimant) and their USDC ATA
-  const claimant = new PublicKey('ReplaceWithClaimantPubkey');
-  const userUsdc = new PublicKey('ReplaceWithClaimantUsdcAta');
 
   const [configPda] = getConfigPda();
   const [premiumPoolPda] = getPremiumPoolPda();
-  // The member PDA can be derived if needed, but is not required here.
+  const [registryPda] = PublicKey.findProgramAddressSync([Buffer.from('reviewer_registry')], PROGRAM_ID);
+  const memberPda = new PublicKey('ReplaceWithMemberPda');
+  // The member's policy determines the annual maximum
+  const member = await program.account.member.fetch(memberPda);
+  // Claims filed for a dependent also need the Dependent account
+  const claim = await program.account.claim.fetch(claimPda);
+  // Allowed amount (at most the billed amount) and the off-chain reason code
+  // for any adjustment; 0 means approved as billed
+  const approvedAmount = claim.amount;
+  const reasonCode = 0;
   const txSig = await program.methods
-    .approveClaim()
+    .approveClaim(approvedAmount, reasonCode)
     .accounts({
       config: configPda,
-      member: new PublicKey('ReplaceWithMemberPda'),
+      member: memberPda,
+      policy: member.policy,
       claim: claimPda,
+      dependent: claim.dependent,
+      // Escalated claims, or claims the registry cannot staff a panel for
+      registry: registryPda,
       authority: wallet.publicKey,
       premiumPool: premiumPoolPda,
-      userUsdcAccount: userUsdc,
+      // The claimant's or provider's USDC account, fixed at submission
+      payeeUsdcAccount: claim.payee,
       tokenProgram: TOKEN_PROGRAM_ID,
     })
     .signers([])
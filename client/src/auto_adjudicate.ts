@@ -37,12 +37,12 @@ async function main() {
 
   // Replace with the claim PDA you wish to evaluate
   const claimPda = new PublicKey('ReplaceWithClaimPda');
-  // Replace with the member PDA and claimant USDC ATA addresses
+  // Replace with the member PDA
   const memberPda = new PublicKey('ReplaceWithMemberPda');
-  const userUsdc = new PublicKey('ReplaceWithClaimantUsdcAta');
 
   const [configPda] = getConfigPda();
   const [premiumPoolPda] = getPremiumPoolPda();
+  const [registryPda] = PublicKey.findProgramAddressSync([Buffer.from('reviewer_registry')], PROGRAM_ID);
 
   const ok = await evaluateClaimWithAI(claimPda);
   if (!ok) {
@@ -51,15 +51,24 @@ async function main() {
   }
 
   console.log('AI agent approved claim; sending transaction...');
+  // The member's policy determines the annual maximum
+  const member = await program.account.member.fetch(memberPda);
+  // Claims filed for a dependent also need the Dependent account
+  const claim = await program.account.claim.fetch(claimPda);
+  // Approve the full billed amount with no adjustment reason
   const txSig = await program.methods
-    .approveClaim()
+    .approveClaim(claim.amount, 0)
     .accounts({
       config: configPda,
       member: memberPda,
+      policy: member.policy,
       claim: claimPda,
+      dependent: claim.dependent,
+      // Escalated claims, or claims the registry cannot staff a panel for
+      registry: registryPda,
       authority: wallet.publicKey,
       premiumPool: premiumPoolPda,
-      userUsdcAccount: userUsdc,
+      payeeUsdcAccount: claim.payee,
       tokenProgram: TOKEN_PROGRAM_ID,
     })
     .signers([])
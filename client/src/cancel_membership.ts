@@ -0,0 +1,84 @@
+/*
+ * Example script to cancel the wallet's membership.  Coverage ends
+ * immediately.  If no claims are pending, the premium refund is paid and the
+ * Member account closed in the same transaction; otherwise run this script
+ * again once the pending claims have been paid or denied.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
+import { PublicKey } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda, getPremiumPoolPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  const usdcMint = new PublicKey('ReplaceWithUsdCMint');
+  const [configPda] = getConfigPda();
+  const [premiumPoolPda] = getPremiumPoolPda();
+  const [memberPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('member'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const [coverageMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from('coverage_mint'), memberPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const coverageToken = await getAssociatedTokenAddress(coverageMint, user.publicKey);
+  const member = await program.account.member.fetch(memberPda);
+  // Each refund goes back to whoever paid that premium period: the last one
+  // and, if it was prepaid, the one before it
+  const refundUsdc = await getAssociatedTokenAddress(usdcMint, member.lastPremiumPayer as PublicKey);
+  const priorPayer = member.priorPremiumPayer as PublicKey;
+  const priorRefundUsdc = priorPayer.equals(PublicKey.default)
+    ? null
+    : await getAssociatedTokenAddress(usdcMint, priorPayer);
+
+  const tx = new web3.Transaction();
+  if (!member.cancelled) {
+    tx.add(
+      await program.methods
+        .cancelMembership()
+        .accounts({
+          config: configPda,
+          member: memberPda,
+          policy: member.policy,
+          authority: user.publicKey,
+          coverageMint,
+          coverageToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction(),
+    );
+  }
+  if (member.openClaims.isZero()) {
+    tx.add(
+      await program.methods
+        .closeMembership()
+        .accounts({
+          config: configPda,
+          member: memberPda,
+          authority: user.publicKey,
+          refundUsdcAccount: refundUsdc,
+          priorRefundUsdcAccount: priorRefundUsdc,
+          premiumPool: premiumPoolPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction(),
+    );
+  } else {
+    console.log('Claims pending; run again to close the membership once they are resolved.');
+  }
+  const txSig = await provider.sendAndConfirm(tx);
+  console.log('Cancellation tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
@@ -0,0 +1,49 @@
+/*
+ * Example script to vote on a governance proposal.  Voting power equals the
+ * APH recorded in the voter's stake account.  Set `support` to false to vote
+ * against the proposal.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  // Replace with the proposal PDA you wish to vote on
+  const proposalPda = new PublicKey('ReplaceWithProposalPda');
+  const support = true;
+
+  const [stakePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('stake'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const [voteRecordPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('vote'), proposalPda.toBuffer(), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+
+  const txSig = await program.methods
+    .castVote(support)
+    .accounts({
+      proposal: proposalPda,
+      stake: stakePda,
+      voteRecord: voteRecordPda,
+      voter: user.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([])
+    .rpc();
+  console.log('Vote transaction:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
@@ -0,0 +1,68 @@
+/*
+ * Example script to switch the wallet's membership to another policy.  This
+ * only succeeds during the open enrollment window configured by governance,
+ * unless the life event attestor co-signs the transaction.  The prorated
+ * premium difference is charged to the user's USDC account, or refunded
+ * to whoever paid the current period.  The switch fails while the member has
+ * open claims.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
+import { PublicKey } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda, getPremiumPoolPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  // Replace with the policy to switch to
+  const newPolicy = new PublicKey('ReplaceWithNewPolicyAddress');
+  const usdcMint = new PublicKey('ReplaceWithUsdCMint');
+  const [configPda] = getConfigPda();
+  const [premiumPoolPda] = getPremiumPoolPda();
+  const [memberPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('member'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const [stakePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('stake'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const member = await program.account.member.fetch(memberPda);
+  const userUsdc = await getAssociatedTokenAddress(usdcMint, user.publicKey);
+  const refundUsdc = await getAssociatedTokenAddress(usdcMint, member.lastPremiumPayer);
+
+  const txSig = await program.methods
+    .changePolicy()
+    .accounts({
+      config: configPda,
+      member: memberPda,
+      authority: user.publicKey,
+      policy: member.policy,
+      newPolicy,
+      // Outside open enrollment, pass the life event attestor and add it as
+      // a signer, or pass the LifeEvent feed and its report on the member
+      attestor: null,
+      lifeEventFeed: null,
+      lifeEventReport: null,
+      // Pass null instead if the user has never staked APH
+      stake: stakePda,
+      userUsdcAccount: userUsdc,
+      refundUsdcAccount: refundUsdc,
+      premiumPool: premiumPoolPda,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([])
+    .rpc();
+  console.log('Policy change tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
@@ -1,3 +1,4 @@
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 
 // Program ID as defined in the Anchor program (see programs/apollo_core/src/lib.rs)
@@ -10,6 +11,7 @@ export const PROGRAM_ID = new PublicKey("Apoll1CCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCcA
 export const CONFIG_SEED = Buffer.from("config");
 export const PREMIUM_POOL_SEED = Buffer.from("premium_pool");
 export const CAPITAL_POOL_SEED = Buffer.from("capital_pool");
+export const PROPOSAL_SEED = Buffer.from("proposal");
 
 /**
  * Derives the config PDA for the Apollo program.
@@ -33,3 +35,13 @@ export function getPremiumPoolPda(): [PublicKey, number] {
 export function getCapitalPoolPda(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([CAPITAL_POOL_SEED], PROGRAM_ID);
 }
+
+/**
+ * Derives the PDA of a governance proposal from its sequential id.
+ */
+export function getProposalPda(id: BN): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [PROPOSAL_SEED, id.toArrayLike(Buffer, "le", 8)],
+    PROGRAM_ID,
+  );
+}
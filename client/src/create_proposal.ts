@@ -0,0 +1,87 @@
+/*
+ * Example script to create a governance proposal.  The proposer must have
+ * staked at least the configured proposal threshold of APH (see stake.ts).
+ * This example proposes a new policy; once the proposal passes, anyone can
+ * run `create_policy` against it to create the policy account.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda, getProposalPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  const [configPda] = getConfigPda();
+  const config: any = await program.account.config.fetch(configPda);
+  const [proposalPda] = getProposalPda(config.nextProposalId);
+  const [stakePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('stake'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+
+  // Replace with the hash of the off-chain proposal description
+  const descriptionHash = Array(32).fill(0);
+  const action = {
+    createPolicy: {
+      terms: {
+        riskPremium: new anchor.BN(95_000_000), // 95 USDC
+        adminFee: new anchor.BN(5_000_000), // 5 USDC
+        coverageLimit: new anchor.BN(10_000_000_000), // 10,000 USDC
+        annualMax: new anchor.BN(100_000_000_000), // 100,000 USDC
+        gracePeriod: new anchor.BN(7 * 24 * 60 * 60), // 7 days
+        costSharing: {
+          deductible: new anchor.BN(1_000_000_000), // 1,000 USDC per year
+          copay: new anchor.BN(25_000_000), // 25 USDC per claim
+          coinsuranceBps: 2000, // member pays 20%
+          outOfPocketMax: new anchor.BN(5_000_000_000), // 5,000 USDC per year
+          familyDeductible: new anchor.BN(3_000_000_000), // 3,000 USDC per year
+        },
+        dependentPremium: new anchor.BN(60_000_000), // 60 USDC per dependent
+        // Applies to claims not paid to a verified provider
+        outOfNetwork: {
+          deductible: new anchor.BN(2_000_000_000), // 2,000 USDC per year
+          copay: new anchor.BN(50_000_000), // 50 USDC per claim
+          coinsuranceBps: 4000, // member pays 40%
+          outOfPocketMax: new anchor.BN(10_000_000_000), // 10,000 USDC per year
+          familyDeductible: new anchor.BN(6_000_000_000), // 6,000 USDC per year
+        },
+      },
+    },
+  };
+  // To reprice an existing policy instead, propose
+  //   { updatePolicy: { policy, effectiveAt, terms } }
+  // and run `update_policy` once it passes; `{ deactivatePolicy: { policy } }`
+  // closes a policy to new enrollments.
+  // An oracle feed is created or reconfigured with
+  //   { configureOracleFeed: { kind: { providerCredential: {} }, signers,
+  //     signerCount, threshold, maxStaleness } }
+  // where `signers` always holds five keys (unused ones set to
+  // PublicKey.default), followed by `configure_oracle_feed` once it passes.
+  // Off-ramp operators for fiat payouts are registered with
+  //   { setOffRampOperator: { authority, usdcAccount, active: true } }
+  // followed by `set_off_ramp_operator`.
+
+  const txSig = await program.methods
+    .createProposal(action, descriptionHash)
+    .accounts({
+      config: configPda,
+      proposal: proposalPda,
+      stake: stakePda,
+      proposer: user.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([])
+    .rpc();
+  console.log('Proposal', proposalPda.toBase58(), 'created, tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
@@ -8,8 +8,12 @@
 
 import * as anchor from '@coral-xyz/anchor';
 import { Program, Wallet, web3 } from '@coral-xyz/anchor';
-import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
-import { PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddress,
+} from '@solana/spl-token';
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from '@solana/web3.js';
 import idl from '../target/idl/apollo_core.json';
 import { PROGRAM_ID, getConfigPda, getPremiumPoolPda } from './constants';
 
@@ -31,6 +35,12 @@ async function main() {
     [Buffer.from('member'), user.publicKey.toBuffer()],
     PROGRAM_ID,
   );
+  // The user's stake account qualifies them for a discount on the
+  // administrative fee portion of the premium.
+  const [stakePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('stake'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
 
   // Determine the user's USDC associated token account.  Replace the USDC
   // mint address with the one used in your deployment.  If the account does
@@ -38,6 +48,14 @@ async function main() {
   const usdcMint = new PublicKey('ReplaceWithUsdCMint');
   const userUsdc = await getAssociatedTokenAddress(usdcMint, user.publicKey);
 
+  // The proof-of-coverage NFT is minted to the user's associated token
+  // account for a mint derived from the member PDA.
+  const [coverageMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from('coverage_mint'), memberPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const coverageToken = await getAssociatedTokenAddress(coverageMint, user.publicKey);
+
   const txSig = await program.methods
     .enrollMember()
     .accounts({
@@ -45,10 +63,16 @@ async function main() {
       policy: policyPubkey,
       member: memberPda,
       authority: user.publicKey,
+      // Pass null instead if the user has never staked APH
+      stake: stakePda,
       userUsdcAccount: userUsdc,
       premiumPool: premiumPoolPda,
+      coverageMint,
+      coverageToken,
       tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
     })
     .signers([])
     .rpc();
@@ -0,0 +1,86 @@
+/*
+ * Example script for an off-ramp operator.  Without a settlement report it
+ * takes up a funded fiat payout request; pay the member's bank account off
+ * chain afterwards.  Once the FiatSettlement oracle feed has posted a report
+ * on the request (see `post_oracle_report.ts`, with the request as subject,
+ * value 1 and the receipt hash as data hash), run it again to release the
+ * escrow to the operator's USDC account.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda } from './constants';
+
+// Index of FiatSettlement in the on-chain FeedKind enum
+const FIAT_SETTLEMENT_FEED = 2;
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  // Replace with the claim whose payout the operator is settling
+  const claimPda = new PublicKey('ReplaceWithClaimPda');
+
+  const [configPda] = getConfigPda();
+  const [requestPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('fiat_payout'), claimPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const [operatorPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('off_ramp'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const request = await program.account.fiatPayoutRequest.fetch(requestPda);
+
+  if (request.status.pending) {
+    const txSig = await program.methods
+      .claimFiatPayout()
+      .accounts({
+        claim: claimPda,
+        request: requestPda,
+        escrow: request.escrow,
+        operator: operatorPda,
+        authority: user.publicKey,
+      })
+      .signers([])
+      .rpc();
+    console.log('Fiat payout taken up, tx:', txSig);
+    return;
+  }
+
+  const operator = await program.account.offRampOperator.fetch(operatorPda);
+  const [feedPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('oracle_feed'), Buffer.from([FIAT_SETTLEMENT_FEED])],
+    PROGRAM_ID,
+  );
+  const [reportPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('oracle_report'), feedPda.toBuffer(), requestPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const txSig = await program.methods
+    .finalizeFiatPayout()
+    .accounts({
+      config: configPda,
+      request: requestPda,
+      escrow: request.escrow,
+      operator: operatorPda,
+      operatorUsdcAccount: operator.usdcAccount,
+      settlementFeed: feedPda,
+      settlementReport: reportPda,
+      payer: request.payer,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([])
+    .rpc();
+  console.log('Fiat payout settled, tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
@@ -0,0 +1,73 @@
+/*
+ * Example script for an employer to pay one period of premiums for every
+ * member on its group roster.  The sponsor's share is debited from the
+ * wallet's USDC account.  Employee contributions are debited from each
+ * employee's USDC account, so every employee must first approve the config
+ * PDA as a delegate for their contribution, e.g.
+ *
+ *   spl-token approve <employee-usdc-account> <amount> <config-pda>
+ *
+ * The config PDA is the delegate for every group, so employees should approve
+ * only the amount of their contribution.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
+import { PublicKey } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda, getPremiumPoolPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const sponsor = wallet.payer as web3.Keypair;
+
+  const usdcMint = new PublicKey('ReplaceWithUsdCMint');
+  const [configPda] = getConfigPda();
+  const [premiumPoolPda] = getPremiumPoolPda();
+  const [groupPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('employer_group'), sponsor.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const group = await program.account.employerGroup.fetch(groupPda);
+  // Once the group's policy version is superseded, pass the next version.
+  const policy = await program.account.policy.fetch(group.policy);
+  const nextPolicy = policy.nextVersion.equals(PublicKey.default) ? null : policy.nextVersion;
+
+  // Each roster entry is passed as its Member account followed by the
+  // employee's USDC account, in roster order.
+  const remainingAccounts = [];
+  for (const memberPda of group.members as PublicKey[]) {
+    const member = await program.account.member.fetch(memberPda);
+    const employeeUsdc = await getAssociatedTokenAddress(usdcMint, member.authority);
+    remainingAccounts.push(
+      { pubkey: memberPda, isSigner: false, isWritable: true },
+      { pubkey: employeeUsdc, isSigner: false, isWritable: true },
+    );
+  }
+  const sponsorUsdc = await getAssociatedTokenAddress(usdcMint, sponsor.publicKey);
+
+  const txSig = await program.methods
+    .payGroupPremium()
+    .accounts({
+      config: configPda,
+      group: groupPda,
+      policy: group.policy,
+      nextPolicy,
+      sponsor: sponsor.publicKey,
+      sponsorUsdcAccount: sponsorUsdc,
+      premiumPool: premiumPoolPda,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .remainingAccounts(remainingAccounts)
+    .signers([])
+    .rpc();
+  console.log('Group premium payment tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
@@ -1,8 +1,10 @@
 /*
  * Example script to pay an additional premium.  This script calls the
- * `pay_premium` instruction.  It assumes that the user is already
- * enrolled in the policy and has sufficient USDC.  See enroll.ts for
- * notes on configuration.
+ * `pay_premium` instruction, which extends the member's paid-through date
+ * by one month.  Coverage can be paid at most one month ahead.  By default
+ * the wallet pays for its own membership; set `memberAuthority` to another
+ * member's wallet to pay on their behalf (for example as an employer or
+ * family member).  See enroll.ts for notes on configuration.
  */
 
 import * as anchor from '@coral-xyz/anchor';
@@ -24,16 +26,52 @@ async function main() {
   const usdcMint = new PublicKey('ReplaceWithUsdCMint');
   const [configPda] = getConfigPda();
   const [premiumPoolPda] = getPremiumPoolPda();
-  const userUsdc = await getAssociatedTokenAddress(usdcMint, user.publicKey);
+  // Wallet of the member whose coverage is being paid for
+  const memberAuthority = user.publicKey;
+  const [memberPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('member'), memberAuthority.toBuffer()],
+    PROGRAM_ID,
+  );
+  // The proof-of-coverage NFT is re-issued here if coverage had lapsed.
+  const [coverageMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from('coverage_mint'), memberPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const coverageToken = await getAssociatedTokenAddress(coverageMint, memberAuthority);
+  // The payer's stake account qualifies them for a discount on the
+  // administrative fee portion of the premium.
+  const [stakePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('stake'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  // Once the member's policy version has been superseded, the version in
+  // effect at the start of the period must be passed so the member can be
+  // moved onto it.  Follow the chain of versions up to that one.
+  const periodStart = Math.floor(Date.now() / 1000);
+  let nextPolicy: PublicKey | null = null;
+  let version = await program.account.policy.fetch(policyPubkey);
+  while (!version.nextVersion.equals(PublicKey.default)) {
+    const next = await program.account.policy.fetch(version.nextVersion);
+    if (next.effectiveAt.toNumber() > periodStart) break;
+    nextPolicy = version.nextVersion;
+    version = next;
+  }
+  const payerUsdc = await getAssociatedTokenAddress(usdcMint, user.publicKey);
 
   const txSig = await program.methods
     .payPremium()
     .accounts({
       config: configPda,
       policy: policyPubkey,
-      authority: user.publicKey,
-      userUsdcAccount: userUsdc,
+      member: memberPda,
+      nextPolicy,
+      payer: user.publicKey,
+      // Pass null instead if the payer has never staked APH
+      stake: stakePda,
+      payerUsdcAccount: payerUsdc,
       premiumPool: premiumPoolPda,
+      coverageMint,
+      coverageToken,
       tokenProgram: TOKEN_PROGRAM_ID,
     })
     .signers([])
@@ -0,0 +1,92 @@
+/*
+ * Example script to post a signed oracle report.  Each oracle signs the
+ * report message off chain; the signatures are checked on chain by Ed25519
+ * program instructions placed in the same transaction as
+ * `post_oracle_report`.
+ *
+ * For local testing the oracles can be mock signers: generate keypairs with
+ * `solana-keygen new -o oracle1.json`, list their public keys in a
+ * `configureOracleFeed` proposal, and pass the keypair files to this script:
+ *
+ *   ts-node src/post_oracle_report.ts oracle1.json oracle2.json
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, web3 } from '@coral-xyz/anchor';
+import { Ed25519Program, PublicKey, SystemProgram, SYSVAR_INSTRUCTIONS_PUBKEY } from '@solana/web3.js';
+import * as fs from 'fs';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID } from './constants';
+
+// Feed kinds in the order of the on-chain FeedKind enum
+const FEED_KINDS = ['providerCredential', 'lifeEvent', 'fiatSettlement'];
+
+/**
+ * Builds the message oracles sign for a report; mirrors
+ * `OracleReport::message` in the program.
+ */
+function reportMessage(
+  feed: PublicKey,
+  subject: PublicKey,
+  value: anchor.BN,
+  dataHash: Buffer,
+  observedAt: anchor.BN,
+): Buffer {
+  return Buffer.concat([
+    feed.toBuffer(),
+    subject.toBuffer(),
+    value.toArrayLike(Buffer, 'le', 8),
+    dataHash,
+    observedAt.toTwos(64).toArrayLike(Buffer, 'le', 8),
+  ]);
+}
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+
+  const signers = process.argv.slice(2).map((path) =>
+    web3.Keypair.fromSecretKey(Uint8Array.from(JSON.parse(fs.readFileSync(path, 'utf8')))),
+  );
+  // Replace with the feed kind, the account the report is about and the
+  // reported value, e.g. 1 to attest a provider's credentials
+  const kind = 'providerCredential';
+  const subject = new PublicKey('ReplaceWithSubjectAddress');
+  const value = new anchor.BN(1);
+  const dataHash = Buffer.alloc(32);
+  const observedAt = new anchor.BN(Math.floor(Date.now() / 1000));
+
+  const [feedPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('oracle_feed'), Buffer.from([FEED_KINDS.indexOf(kind)])],
+    PROGRAM_ID,
+  );
+  const [reportPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('oracle_report'), feedPda.toBuffer(), subject.toBuffer()],
+    PROGRAM_ID,
+  );
+  const message = reportMessage(feedPda, subject, value, dataHash, observedAt);
+
+  const tx = new web3.Transaction();
+  for (const signer of signers) {
+    tx.add(Ed25519Program.createInstructionWithPrivateKey({ privateKey: signer.secretKey, message }));
+  }
+  tx.add(
+    await program.methods
+      .postOracleReport(subject, value, Array.from(dataHash), observedAt)
+      .accounts({
+        feed: feedPda,
+        report: reportPda,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction(),
+  );
+  const txSig = await provider.sendAndConfirm(tx);
+  console.log('Oracle report', reportPda.toBase58(), 'posted, tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
@@ -0,0 +1,50 @@
+/*
+ * Example script to register the wallet as a healthcare provider.  Claims
+ * that name the provider are paid directly into the wallet's USDC account.
+ * The provider starts unverified: its claims are out of network until the
+ * provider attestor or a governance vote verifies its credentials.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { getAssociatedTokenAddress } from '@solana/spl-token';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  const usdcMint = new PublicKey('ReplaceWithUsdCMint');
+  // Hash of the provider's NPI and its specialty code
+  const npiHash = Array(32).fill(0);
+  const specialty = 0;
+
+  const [configPda] = getConfigPda();
+  const [providerPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('provider'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const usdcAccount = await getAssociatedTokenAddress(usdcMint, user.publicKey);
+
+  const txSig = await program.methods
+    .registerProvider(npiHash, specialty)
+    .accounts({
+      config: configPda,
+      provider: providerPda,
+      usdcAccount,
+      authority: user.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([])
+    .rpc();
+  console.log('Provider', providerPda.toBase58(), 'registered, tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
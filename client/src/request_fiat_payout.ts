@@ -0,0 +1,108 @@
+/*
+ * Example script for a member to receive a claim in fiat instead of USDC.
+ * The claim's payout is redirected into a program-owned escrow; if the claim
+ * was already paid, the amount paid is moved from the member's USDC account
+ * into the escrow.  Once the escrow is funded, a registered off-ramp operator
+ * takes up the request and pays the member's bank account.  If no operator
+ * takes it up within 3 days, or the operator does not settle within 7 days,
+ * anyone can run this script again to refund the escrow to the member's USDC
+ * account.  If the claim is denied before the escrow is funded, running it
+ * again cancels the request.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  // Replace with the claim PDA and the USDC mint
+  const claimPda = new PublicKey('ReplaceWithClaimPda');
+  const usdcMint = new PublicKey('ReplaceWithUsdCMint');
+  // Hash of the member's off-chain bank details, shared with the operator
+  const bankDetailsHash = Array(32).fill(0);
+
+  const [configPda] = getConfigPda();
+  const [memberPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('member'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const [requestPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('fiat_payout'), claimPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const [escrowPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('fiat_escrow'), claimPda.toBuffer()],
+    PROGRAM_ID,
+  );
+
+  const claim = await program.account.claim.fetch(claimPda);
+  const existing = await program.account.fiatPayoutRequest.fetchNullable(requestPda);
+  if (existing && claim.status.denied) {
+    const txSig = await program.methods
+      .cancelFiatPayout()
+      .accounts({
+        config: configPda,
+        member: memberPda,
+        claim: claimPda,
+        request: requestPda,
+        escrow: escrowPda,
+        authority: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([])
+      .rpc();
+    console.log('Fiat payout cancelled, tx:', txSig);
+    return;
+  }
+  if (existing) {
+    const txSig = await program.methods
+      .expireFiatPayout()
+      .accounts({
+        config: configPda,
+        claim: claimPda,
+        request: requestPda,
+        escrow: escrowPda,
+        refundAccount: existing.refundAccount,
+        payer: existing.payer,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([])
+      .rpc();
+    console.log('Fiat payout refunded, tx:', txSig);
+    return;
+  }
+  // A claim already paid to the member is funded from the account it was paid to
+  const paid = claim.status.paid || claim.status.partiallyApproved;
+
+  const txSig = await program.methods
+    .requestFiatPayout(bankDetailsHash)
+    .accounts({
+      config: configPda,
+      member: memberPda,
+      claim: claimPda,
+      request: requestPda,
+      escrow: escrowPda,
+      usdcMint,
+      userUsdcAccount: paid ? claim.payee : null,
+      authority: user.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([])
+    .rpc();
+  console.log('Fiat payout requested', requestPda.toBase58(), 'tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
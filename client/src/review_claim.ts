@@ -0,0 +1,75 @@
+/*
+ * Example script for a panel reviewer.  Posts the reviewer's APH bond for a
+ * claim under review and casts a vote.  The reviewer must have been drawn
+ * onto the claim's panel by `select_review_panel`.  Use
+ * `{ partial: { amount } }` as the vote to approve a lower amount.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const user = wallet.payer as web3.Keypair;
+
+  // Replace with the claim PDA under review and the APH mint
+  const claimPda = new PublicKey('ReplaceWithClaimPda');
+  const aphMint = new PublicKey('ReplaceWithAPhMint');
+  const vote = { approve: {} };
+
+  const [configPda] = getConfigPda();
+  const [panelPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('review_panel'), claimPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const [reviewerPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('reviewer'), user.publicKey.toBuffer()],
+    PROGRAM_ID,
+  );
+  const [bondVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('bond_vault'), claimPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const userAph = await getAssociatedTokenAddress(aphMint, user.publicKey);
+
+  const bondSig = await program.methods
+    .postReviewBond()
+    .accounts({
+      config: configPda,
+      claim: claimPda,
+      panel: panelPda,
+      reviewer: reviewerPda,
+      bondVault: bondVaultPda,
+      aphMint,
+      authority: user.publicKey,
+      userAphAccount: userAph,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([])
+    .rpc();
+  console.log('Bond posted, tx:', bondSig);
+
+  const voteSig = await program.methods
+    .castClaimVote(vote)
+    .accounts({
+      claim: claimPda,
+      panel: panelPda,
+      authority: user.publicKey,
+    })
+    .signers([])
+    .rpc();
+  console.log('Vote cast, tx:', voteSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
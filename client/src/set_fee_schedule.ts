@@ -0,0 +1,54 @@
+/*
+ * Example script for the price oracle to publish the fee schedule of a
+ * procedure category.  The allowed amount caps payouts for claims priced
+ * against the schedule.  The billed amounts are market prices at the 50th,
+ * 75th, 90th and 95th percentiles; claims billed above the percentile chosen
+ * by governance are flagged for review.
+ */
+
+import * as anchor from '@coral-xyz/anchor';
+import { Program, Wallet, web3 } from '@coral-xyz/anchor';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
+import idl from '../target/idl/apollo_core.json';
+import { PROGRAM_ID, getConfigPda } from './constants';
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = new Program(idl as anchor.Idl, PROGRAM_ID, provider);
+  const wallet = provider.wallet as Wallet;
+  const oracle = wallet.payer as web3.Keypair;
+
+  const procedureCategory = 1;
+  const allowedAmount = new anchor.BN(150_000_000); // 150 USDC
+  const billedPercentiles = [
+    new anchor.BN(140_000_000), // p50
+    new anchor.BN(180_000_000), // p75
+    new anchor.BN(240_000_000), // p90
+    new anchor.BN(300_000_000), // p95
+  ];
+
+  const [configPda] = getConfigPda();
+  const categoryBytes = Buffer.alloc(2);
+  categoryBytes.writeUInt16LE(procedureCategory);
+  const [feeSchedulePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('fee_schedule'), categoryBytes],
+    PROGRAM_ID,
+  );
+
+  const txSig = await program.methods
+    .setFeeSchedule(procedureCategory, allowedAmount, billedPercentiles)
+    .accounts({
+      config: configPda,
+      feeSchedule: feeSchedulePda,
+      oracle: oracle.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([])
+    .rpc();
+  console.log('Fee schedule', feeSchedulePda.toBase58(), 'updated, tx:', txSig);
+}
+
+main().catch((err) => {
+  console.error(err);
+});
@@ -2,6 +2,9 @@
  * Example script to submit a claim.  It calls the `submit_claim` instruction
  * on the Apollo program.  Provide the claim amount and a 32‑byte hash of
  * the off‑chain documentation (for example the SHA‑256 hash of an invoice).
+ * By default the claim reimburses the wallet's own USDC account at
+ * out-of-network rates; to have a registered provider paid directly, pass
+ * its Provider PDA and pay into the provider's USDC account instead.
  */
 
 import * as anchor from '@coral-xyz/anchor';
@@ -24,6 +27,21 @@ async function main() {
   const amount = 100_000; // Claim amount (e.g. 0.1 USDC with 6 decimals)
   // Provide a 32‑byte hash.  For testing you can use an array of zeros.
   const offchainHash = new Uint8Array(32);
+  // Structured description of the service.  It may be null only while no
+  // price oracle is configured.
+  // Service dates are unix timestamps and must fall within the coverage
+  // period.  Categories are coarse (0 = not given), and the full codes are
+  // only committed to by a salted hash.
+  const now = Math.floor(Date.now() / 1000);
+  const metadata = {
+    claimType: { medical: {} },
+    serviceStart: new anchor.BN(now - 24 * 60 * 60),
+    serviceEnd: new anchor.BN(now - 24 * 60 * 60),
+    procedureCategory: 0,
+    diagnosisCategory: 0,
+    codesHash: Array(32).fill(0),
+    provider: null,
+  };
 
   const [configPda] = getConfigPda();
   const [memberPda] = PublicKey.findProgramAddressSync(
@@ -31,22 +49,55 @@ async function main() {
     PROGRAM_ID,
   );
   const [premiumPoolPda] = getPremiumPoolPda();
+  // Claims are addressed by the member's enrollment id and claim count
+  const member = await program.account.member.fetch(memberPda);
   const [claimPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from('claim'), memberPda.toBuffer(), Buffer.from([0, 0, 0, 0, 0, 0, 0, 0])],
+    [
+      Buffer.from('claim'),
+      memberPda.toBuffer(),
+      member.enrollmentId.toArrayLike(Buffer, 'le', 8),
+      member.claimCount.toArrayLike(Buffer, 'le', 8),
+    ],
+    PROGRAM_ID,
+  );
+  const payeeUsdc = await getAssociatedTokenAddress(usdcMint, user.publicKey);
+  // The proof-of-coverage NFT minted at enrollment
+  const [coverageMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from('coverage_mint'), memberPda.toBuffer()],
+    PROGRAM_ID,
+  );
+  const coverageToken = await getAssociatedTokenAddress(coverageMint, user.publicKey);
+  // Address of the fee schedule for the procedure category, passed whether
+  // or not a schedule has been published there
+  const category = Buffer.alloc(2);
+  category.writeUInt16LE(metadata.procedureCategory);
+  const [feeSchedulePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('fee_schedule'), category],
     PROGRAM_ID,
   );
-  const userUsdc = await getAssociatedTokenAddress(usdcMint, user.publicKey);
 
   const txSig = await program.methods
-    .submitClaim(new anchor.BN(amount), Array.from(offchainHash))
+    .submitClaim(new anchor.BN(amount), Array.from(offchainHash), metadata)
     .accounts({
       config: configPda,
       member: memberPda,
       authority: user.publicKey,
       policy: policyPubkey,
+      // Pass the Dependent PDA instead to file the claim for a dependent
+      dependent: null,
+      // Pass a Provider PDA to pay that provider directly; the payee must then
+      // be the provider's registered USDC account
+      provider: null,
+      // With a provider, a fresh ProviderCredential oracle report on it can
+      // establish in-network status; pass the feed and report PDAs
+      credentialFeed: null,
+      credentialReport: null,
+      // Required with metadata; pass null only when metadata is null
+      feeSchedule: feeSchedulePda,
+      coverageToken,
       claim: claimPda,
       premiumPool: premiumPoolPda,
-      userUsdcAccount: userUsdc,
+      payeeUsdcAccount: payeeUsdc,
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
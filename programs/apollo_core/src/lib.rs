@@ -1,28 +1,135 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_lang::solana_program::{ed25519_program, hash::hashv, sysvar};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{
+    self, Approve, Burn, CloseAccount, FreezeAccount, Mint, MintTo, ThawAccount, Token, TokenAccount, Transfer,
+};
 
 /// ApolloCare v1 core program.
 ///
 /// This program implements a minimal subset of the architecture described in the
-/// ApolloCare whitepaper.  It allows an authority to configure the protocol,
-/// create insurance policies, enroll members, accept monthly premiums, accept
-/// staking of the APH token and submit/approve claims.  It does **not**
-/// implement full DAO governance, TWAB accounting, decentralized claims
-/// adjudication or oracles; those features are left to future versions.
+/// ApolloCare whitepaper.  It allows APH stakers to govern the protocol through
+/// on‑chain proposals, create insurance policies, enroll members, accept monthly
+/// premiums, accept staking of the APH token with TWAB accounting, and
+/// submit claims that are either paid automatically or adjudicated by a
+/// randomly selected panel of bonded reviewers.  Off‑chain data is brought on
+/// chain through oracle feeds whose reports are signed by an m‑of‑n signer
+/// set chosen by governance.
 
 declare_id!("Apoll1CCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCcApH");
 
+/// Default length of the voting window for governance proposals (3 days).
+pub const DEFAULT_VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;
+/// Default minimum number of votes (in APH base units) that must be cast for a
+/// proposal to be valid.
+pub const DEFAULT_QUORUM_VOTES: u64 = 1_000_000_000_000;
+/// Default minimum stake (in APH base units) required to create a proposal.
+pub const DEFAULT_PROPOSAL_THRESHOLD: u64 = 10_000_000_000;
+/// Time after a proposal's voting window closes during which it may be
+/// executed if it passed (7 days).  Afterwards it can only be defeated.
+pub const EXECUTION_WINDOW: i64 = 7 * 24 * 60 * 60;
+/// Number of voting power checkpoints retained per Stake account.
+pub const STAKE_CHECKPOINTS: usize = 32;
+/// Number of TWAB observations retained per Stake account.
+pub const TWAB_OBSERVATIONS: usize = 32;
+/// Number of staking discount tiers (Bronze, Silver, Gold, Platinum,
+/// Enterprise).
+pub const DISCOUNT_TIERS: usize = 5;
+/// Default window over which a staker's TWAB is measured for tier
+/// eligibility (30 days).
+pub const DEFAULT_TIER_TWAB_WINDOW: i64 = 30 * 24 * 60 * 60;
+/// Denominator for values expressed in basis points.
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// Length of the coverage period bought by one monthly premium (30 days).
+pub const PREMIUM_PERIOD: i64 = 30 * 24 * 60 * 60;
+/// Length of a member's benefit year, over which annual maximums apply.
+pub const BENEFIT_YEAR: i64 = 365 * 24 * 60 * 60;
+/// Maximum number of dependents covered under one subscriber.
+pub const MAX_DEPENDENTS: u8 = 10;
+/// Maximum number of members on an employer group's roster.  Bounded by the
+/// accounts that fit in one `pay_group_premium` transaction.
+pub const MAX_GROUP_MEMBERS: usize = 20;
+/// Reason code recorded when a claim is approved for the billed amount.
+/// Other codes are defined off chain by the claims authority.
+pub const REASON_NONE: u16 = 0;
+/// Reason code recorded when a review panel approves less than the billed
+/// amount.
+pub const REASON_PANEL_ADJUSTED: u16 = 1;
+/// Reason code recorded when a fee schedule reduced the approved amount.
+pub const REASON_FEE_SCHEDULE: u16 = 2;
+/// Number of percentiles of market billed amounts in each fee schedule.
+pub const FEE_PERCENTILES: usize = 4;
+/// Percentiles of market billed amounts recorded in each fee schedule.
+pub const FEE_SCHEDULE_PERCENTILES: [u8; FEE_PERCENTILES] = [50, 75, 90, 95];
+/// Default percentile above which billed amounts are flagged.
+pub const DEFAULT_PRICE_FLAG_PERCENTILE: u8 = 90;
+/// Maximum number of signers in an oracle feed's signer set.
+pub const MAX_ORACLE_SIGNERS: usize = 5;
+/// Time an off‑ramp operator has to take up a funded fiat payout request
+/// before it is refunded to the member (3 days).
+pub const FIAT_CLAIM_WINDOW: i64 = 3 * 24 * 60 * 60;
+/// Time an off‑ramp operator has to settle a fiat payout it took up before
+/// it is refunded to the member (7 days).
+pub const FIAT_SETTLEMENT_WINDOW: i64 = 7 * 24 * 60 * 60;
+/// Maximum number of times a denied claim may be appealed.
+pub const MAX_APPEALS: u8 = 2;
+/// Maximum number of reviewers in the reviewer registry.
+pub const MAX_REVIEWERS: usize = 64;
+/// Number of reviewers on a claims review panel.
+pub const PANEL_SIZE: usize = 5;
+/// Number of slots between a claim entering review and the slot whose hash
+/// seeds its panel selection.
+pub const REVIEW_SEED_DELAY: u64 = 8;
+/// Number of recent slots whose hashes the SlotHashes sysvar is guaranteed
+/// to hold.
+pub const SLOT_HASHES_RETAINED: u64 = anchor_lang::solana_program::slot_hashes::MAX_ENTRIES as u64;
+/// Default minimum stake (in APH base units) required to register as a
+/// claims reviewer.
+pub const DEFAULT_MIN_REVIEWER_STAKE: u64 = 5_000_000_000;
+/// Default review bond as a fraction of the claim amount, in basis points.
+pub const DEFAULT_REVIEW_BOND_BPS: u16 = 1_000;
+/// Maximum reviewer reputation score.
+pub const MAX_REPUTATION: u16 = 1_000;
+/// Reputation assigned to newly registered reviewers.
+pub const INITIAL_REPUTATION: u16 = 500;
+/// Reviewers at or above this reputation post a discounted bond.
+pub const HIGH_REPUTATION: u16 = 750;
+/// Reviewers below this reputation post an increased bond.
+pub const LOW_REPUTATION: u16 = 250;
+/// Default time a review panel has to reach a decision (3 days).
+pub const DEFAULT_REVIEW_PERIOD: i64 = 3 * 24 * 60 * 60;
+/// Number of matching panel votes that decides a claim before the deadline.
+pub const PANEL_MAJORITY: usize = PANEL_SIZE / 2 + 1;
+/// Default share of a dissenting or absent reviewer's bond that is slashed,
+/// in basis points.
+pub const DEFAULT_REVIEW_SLASH_BPS: u16 = 5_000;
+/// Default APH reward for a consensus reviewer, as a fraction of their bond
+/// in basis points.
+pub const DEFAULT_REVIEW_REWARD_BPS: u16 = 1_000;
+/// Default protocol fee share paid to a consensus reviewer, as a fraction of
+/// the claim amount in basis points.
+pub const DEFAULT_REVIEW_FEE_BPS: u16 = 50;
+/// Reputation gained for voting with the panel outcome.
+pub const REPUTATION_CONSENSUS_GAIN: u16 = 20;
+/// Reputation lost for voting against the panel outcome.
+pub const REPUTATION_DISSENT_PENALTY: u16 = 50;
+/// Reputation lost for failing to vote.
+pub const REPUTATION_MISSED_PENALTY: u16 = 30;
+
 #[program]
 pub mod apollo_core {
     use super::*;
 
     /// Initializes the protocol configuration and optionally creates the
     /// program‑owned premium and capital pools.  The signer of this transaction
-    /// becomes the protocol authority.  The USDC and APH mint addresses are
-    /// stored for later validation.  The fast claim threshold controls the
-    /// maximum claim amount that is automatically approved without manual
-    /// intervention.  All amounts are expressed in the smallest unit of the
-    /// respective token (e.g. USDC has 6 decimals).
+    /// becomes the initial claims authority; after initialization the
+    /// authority can only be replaced through a governance proposal.  The USDC
+    /// and APH mint addresses are stored for later validation.  The fast claim
+    /// threshold controls the maximum claim amount that is automatically
+    /// approved without manual intervention.  Governance parameters start at
+    /// their defaults and may be changed by proposal.  All amounts are
+    /// expressed in the smallest unit of the respective token (e.g. USDC has 6
+    /// decimals).
     pub fn initialize(
         ctx: Context<Initialize>,
         usdc_mint: Pubkey,
@@ -35,45 +142,292 @@ pub mod apollo_core {
         cfg.aph_mint = aph_mint;
         cfg.fast_claim_threshold = fast_claim_threshold;
         cfg.next_policy_id = 0;
-        cfg.bump = *ctx.bumps.get("config").unwrap();
+        cfg.voting_period = DEFAULT_VOTING_PERIOD;
+        cfg.quorum_votes = DEFAULT_QUORUM_VOTES;
+        cfg.proposal_threshold = DEFAULT_PROPOSAL_THRESHOLD;
+        cfg.next_proposal_id = 0;
+        cfg.discount_tiers = DiscountTier::defaults();
+        cfg.tier_twab_window = DEFAULT_TIER_TWAB_WINDOW;
+        cfg.min_reviewer_stake = DEFAULT_MIN_REVIEWER_STAKE;
+        cfg.review_bond_bps = DEFAULT_REVIEW_BOND_BPS;
+        cfg.review_period = DEFAULT_REVIEW_PERIOD;
+        cfg.review_slash_bps = DEFAULT_REVIEW_SLASH_BPS;
+        cfg.review_reward_bps = DEFAULT_REVIEW_REWARD_BPS;
+        cfg.review_fee_bps = DEFAULT_REVIEW_FEE_BPS;
+        cfg.protocol_fees = 0;
+        cfg.open_enrollment_start = 0;
+        cfg.open_enrollment_end = 0;
+        cfg.life_event_attestor = Pubkey::default();
+        cfg.next_enrollment_id = 0;
+        cfg.provider_attestor = Pubkey::default();
+        cfg.price_oracle = Pubkey::default();
+        cfg.price_flag_percentile = DEFAULT_PRICE_FLAG_PERCENTILE;
+        cfg.bump = ctx.bumps.config;
         Ok(())
     }
 
-    /// Creates a new insurance policy.  Only the protocol authority may call
-    /// this instruction.  A policy defines a monthly premium (in USDC) and a
-    /// coverage limit (maximum claim amount per claim).  Policies are stored
-    /// as separate accounts so that they can be upgraded or deactivated
-    /// individually.
-    pub fn create_policy(
-        ctx: Context<CreatePolicy>,
-        monthly_premium: u64,
-        coverage_limit: u64,
-    ) -> Result<()> {
-        require!(monthly_premium > 0, ApolloError::InvalidParameter);
-        require!(coverage_limit > 0, ApolloError::InvalidParameter);
-        // Only the authority can create policies.
-        require_keys_eq!(ctx.accounts.authority.key(), ctx.accounts.config.authority, ApolloError::Unauthorized);
+    /// Creates the program‑owned USDC premium pool and APH capital pool.
+    /// Anyone may call this once after `initialize`.  Both pools live at
+    /// fixed PDAs so that no other token account owned by the config PDA,
+    /// such as a bond vault or fiat escrow, can be passed in their place.
+    pub fn init_pools(_ctx: Context<InitPools>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Creates a new insurance policy from a passed governance proposal.  This
+    /// is the executor for `ProposalAction::CreatePolicy`: because it must
+    /// allocate a new Policy account it cannot be run by `execute_proposal`.
+    /// Anyone may call it once the proposal's voting window has closed with a
+    /// successful outcome; the caller pays for the account.  A policy defines
+    /// a monthly premium (in USDC), split into a risk premium and an
+    /// administrative fee, a coverage limit (maximum claim amount per claim),
+    /// an annual maximum on benefits paid per member, the member's cost
+    /// sharing and the grace period after a missed payment before coverage
+    /// lapses.  Policies are stored as separate accounts so that they can be
+    /// upgraded or deactivated individually.  The new policy is version 1 and
+    /// is open for enrollment immediately.
+    pub fn create_policy(ctx: Context<CreatePolicy>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.require_executable(now)?;
+        let terms = match proposal.action {
+            ProposalAction::CreatePolicy { terms } => terms,
+            _ => return err!(ApolloError::InvalidProposalAction),
+        };
         let policy = &mut ctx.accounts.policy;
-        policy.creator = ctx.accounts.authority.key();
-        policy.monthly_premium = monthly_premium;
-        policy.coverage_limit = coverage_limit;
-        policy.bump = *ctx.bumps.get("policy").unwrap();
+        policy.creator = proposal.proposer;
+        policy.set_terms(&terms);
+        policy.version = 1;
+        policy.first_version = policy.key();
+        policy.effective_at = now;
+        policy.superseded_at = i64::MAX;
+        policy.next_version = Pubkey::default();
+        policy.active = true;
+        policy.bump = ctx.bumps.policy;
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = now;
         // Increment the next policy id counter in the config.
         let cfg = &mut ctx.accounts.config;
         cfg.next_policy_id = cfg.next_policy_id.checked_add(1).unwrap();
         Ok(())
     }
 
+    /// Publishes a new version of a policy from a passed `UpdatePolicy`
+    /// proposal.  Like `create_policy` this allocates a new Policy account,
+    /// so anyone may call it and pays for the account.  The new version takes
+    /// effect at the proposal's effective date, or immediately if that date
+    /// has passed.  From then on new members enroll in the new version and
+    /// the old version stops accepting enrollments.  Existing members keep
+    /// the terms of their version until their next premium period that
+    /// starts on or after the effective date, when `pay_premium` moves them
+    /// to the new version.
+    pub fn update_policy(ctx: Context<UpdatePolicy>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.require_executable(now)?;
+        let (target, effective_at, terms) = match proposal.action {
+            ProposalAction::UpdatePolicy { policy, effective_at, terms } => (policy, effective_at.max(now), terms),
+            _ => return err!(ApolloError::InvalidProposalAction),
+        };
+        let current = &mut ctx.accounts.current_policy;
+        require_keys_eq!(current.key(), target, ApolloError::InvalidParameter);
+        require!(current.active, ApolloError::PolicyInactive);
+        require!(current.next_version == Pubkey::default(), ApolloError::PolicyAlreadyUpdated);
+        let policy = &mut ctx.accounts.policy;
+        policy.creator = proposal.proposer;
+        policy.set_terms(&terms);
+        policy.version = current.version.checked_add(1).unwrap();
+        policy.first_version = current.first_version;
+        policy.effective_at = effective_at;
+        policy.superseded_at = i64::MAX;
+        policy.next_version = Pubkey::default();
+        policy.active = true;
+        policy.bump = ctx.bumps.policy;
+        current.next_version = policy.key();
+        current.superseded_at = effective_at;
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = now;
+        let cfg = &mut ctx.accounts.config;
+        cfg.next_policy_id = cfg.next_policy_id.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    /// Deactivates a policy version from a passed `DeactivatePolicy`
+    /// proposal.  Anyone may call it once the proposal has passed.  A
+    /// deactivated policy accepts no new enrollments and cannot be updated,
+    /// but existing members keep their coverage and may keep paying
+    /// premiums.
+    pub fn deactivate_policy(ctx: Context<DeactivatePolicy>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.require_executable(now)?;
+        let target = match proposal.action {
+            ProposalAction::DeactivatePolicy { policy } => policy,
+            _ => return err!(ApolloError::InvalidProposalAction),
+        };
+        let policy = &mut ctx.accounts.policy;
+        require_keys_eq!(policy.key(), target, ApolloError::InvalidParameter);
+        require!(policy.active, ApolloError::PolicyInactive);
+        policy.active = false;
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = now;
+        Ok(())
+    }
+
+    /// Creates a governance proposal.  The current slot is recorded as the
+    /// proposal's snapshot: all voting power, including the proposer's, is
+    /// read from Stake checkpoints written before that slot.  The proposer
+    /// must have held at least `Config.proposal_threshold` APH at the
+    /// snapshot.  The action is validated up front so that a proposal which
+    /// could never be executed is rejected immediately.  The description hash
+    /// refers to an off‑chain document describing the rationale.  Voting opens
+    /// immediately and lasts for `Config.voting_period` seconds.
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: ProposalAction,
+        description_hash: [u8; 32],
+    ) -> Result<()> {
+        action.validate()?;
+        let cfg = &mut ctx.accounts.config;
+        let clock = Clock::get()?;
+        let power = ctx.accounts.stake.voting_power_at(clock.slot);
+        require!(power >= cfg.proposal_threshold, ApolloError::InsufficientVotingPower);
+        let now = clock.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.id = cfg.next_proposal_id;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.action = action;
+        proposal.description_hash = description_hash;
+        proposal.created_at = now;
+        proposal.voting_ends_at = now.checked_add(cfg.voting_period).unwrap();
+        proposal.snapshot_slot = clock.slot;
+        proposal.quorum_votes = cfg.quorum_votes;
+        proposal.for_votes = 0;
+        proposal.against_votes = 0;
+        proposal.status = ProposalStatus::Active;
+        proposal.executed_at = 0;
+        proposal.bump = ctx.bumps.proposal;
+        cfg.next_proposal_id = cfg.next_proposal_id.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    /// Casts a vote on an active proposal.  Voting power equals the APH the
+    /// voter had staked at the proposal's snapshot slot, so stake added after
+    /// the proposal was created carries no weight.  A VoteRecord account is
+    /// created for each (proposal, voter) pair, which prevents double voting.
+    pub fn cast_vote(ctx: Context<CastVote>, support: bool) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        require!(proposal.status == ProposalStatus::Active, ApolloError::InvalidProposalStatus);
+        require!(now <= proposal.voting_ends_at, ApolloError::VotingClosed);
+        let weight = ctx.accounts.stake.voting_power_at(proposal.snapshot_slot);
+        require!(weight > 0, ApolloError::InsufficientVotingPower);
+        if support {
+            proposal.for_votes = proposal.for_votes.checked_add(weight).unwrap();
+        } else {
+            proposal.against_votes = proposal.against_votes.checked_add(weight).unwrap();
+        }
+        let vote = &mut ctx.accounts.vote_record;
+        vote.proposal = proposal.key();
+        vote.voter = ctx.accounts.voter.key();
+        vote.weight = weight;
+        vote.support = support;
+        vote.bump = ctx.bumps.vote_record;
+        Ok(())
+    }
+
+    /// Finalizes a proposal after its voting window has closed.  Anyone may
+    /// call this.  If the proposal failed to reach quorum or a majority, or
+    /// passed but was not executed within `EXECUTION_WINDOW`, it is marked
+    /// `Defeated`.  Otherwise its action is applied to the config and it is
+    /// marked `Executed`.  Passed actions with dedicated executor
+    /// instructions (such as `CreatePolicy`) are rejected here, so that only
+    /// their executor applies them.  Appeals are always finalized by
+    /// `resolve_appeal`, which also returns the claim to `Denied`.
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        require!(proposal.status == ProposalStatus::Active, ApolloError::InvalidProposalStatus);
+        require!(now > proposal.voting_ends_at, ApolloError::VotingStillOpen);
+        require!(
+            !matches!(proposal.action, ProposalAction::OverturnDenial { .. }),
+            ApolloError::InvalidProposalAction
+        );
+        if !proposal.has_passed(now) || proposal.is_expired(now) {
+            proposal.status = ProposalStatus::Defeated;
+            return Ok(());
+        }
+        require!(!proposal.action.has_executor(), ApolloError::InvalidProposalAction);
+        let cfg = &mut ctx.accounts.config;
+        match proposal.action {
+            ProposalAction::SetAuthority { authority } => cfg.authority = authority,
+            ProposalAction::SetFastClaimThreshold { threshold } => cfg.fast_claim_threshold = threshold,
+            ProposalAction::SetGovernanceParams { voting_period, quorum_votes, proposal_threshold } => {
+                cfg.voting_period = voting_period;
+                cfg.quorum_votes = quorum_votes;
+                cfg.proposal_threshold = proposal_threshold;
+            }
+            ProposalAction::SetDiscountTiers { tiers, twab_window } => {
+                cfg.discount_tiers = tiers;
+                cfg.tier_twab_window = twab_window;
+            }
+            ProposalAction::SetReviewParams {
+                min_reviewer_stake,
+                review_bond_bps,
+                review_period,
+                review_slash_bps,
+                review_reward_bps,
+                review_fee_bps,
+            } => {
+                cfg.min_reviewer_stake = min_reviewer_stake;
+                cfg.review_bond_bps = review_bond_bps;
+                cfg.review_period = review_period;
+                cfg.review_slash_bps = review_slash_bps;
+                cfg.review_reward_bps = review_reward_bps;
+                cfg.review_fee_bps = review_fee_bps;
+            }
+            ProposalAction::SetEnrollmentParams { open_enrollment_start, open_enrollment_end, life_event_attestor } => {
+                cfg.open_enrollment_start = open_enrollment_start;
+                cfg.open_enrollment_end = open_enrollment_end;
+                cfg.life_event_attestor = life_event_attestor;
+            }
+            ProposalAction::SetProviderAttestor { attestor } => cfg.provider_attestor = attestor,
+            ProposalAction::SetPricingParams { price_oracle, price_flag_percentile } => {
+                cfg.price_oracle = price_oracle;
+                cfg.price_flag_percentile = price_flag_percentile;
+            }
+            ProposalAction::CreatePolicy { .. }
+            | ProposalAction::UpdatePolicy { .. }
+            | ProposalAction::DeactivatePolicy { .. }
+            | ProposalAction::OverturnDenial { .. }
+            | ProposalAction::VerifyProvider { .. }
+            | ProposalAction::ConfigureOracleFeed { .. }
+            | ProposalAction::SetOffRampOperator { .. } => return err!(ApolloError::InvalidProposalAction),
+        }
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = now;
+        Ok(())
+    }
+
     /// Enrolls a member into a policy.  The member pays the first monthly
-    /// premium in USDC, which is transferred into the premium pool.  A
-    /// Member account is created to track the user’s policy and status.  This
-    /// instruction requires the user to have a USDC token account from which
-    /// the premium will be debited.  The protocol does not mint a membership
-    /// NFT in v1; instead the membership is tracked in the on‑chain Member
-    /// account.
+    /// premium in USDC, which is transferred into the premium pool and covers
+    /// the member for one `PREMIUM_PERIOD` from now.  If the
+    /// user passes their Stake account, the administrative fee portion of the
+    /// premium is discounted according to the tier their TWAB qualifies for.
+    /// A Member account is created to track the user’s policy and status.
+    /// This instruction requires the user to have a USDC token account from
+    /// which the premium will be debited.
+    ///
+    /// A proof‑of‑coverage NFT is minted to the user's associated token
+    /// account.  The NFT mint is a PDA of the member account with the config
+    /// PDA as mint and freeze authority.  After minting, the config PDA is
+    /// approved as delegate for the single token and the token account is
+    /// frozen, so the NFT cannot be transferred and the program can later
+    /// thaw and burn it when coverage ends.
     pub fn enroll_member(ctx: Context<EnrollMember>) -> Result<()> {
-        let cfg = &ctx.accounts.config;
         let policy = &ctx.accounts.policy;
+        let now = Clock::get()?.unix_timestamp;
+        let quote = quote_premium(&ctx.accounts.config, policy, 0, ctx.accounts.stake.as_deref(), now);
         // Transfer the premium from the user to the premium pool.
         let cpi_accounts = Transfer {
             from: ctx.accounts.user_usdc_account.to_account_info(),
@@ -81,41 +435,560 @@ pub mod apollo_core {
             authority: ctx.accounts.authority.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        token::transfer(CpiContext::new(cpi_program, cpi_accounts), policy.monthly_premium)?;
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), quote.amount)?;
+        let cfg = &mut ctx.accounts.config;
+        cfg.protocol_fees = cfg.protocol_fees.checked_add(quote.fee).unwrap();
+        let enrollment_id = cfg.next_enrollment_id;
+        cfg.next_enrollment_id = cfg.next_enrollment_id.checked_add(1).unwrap();
 
         // Initialize the member.
         let member = &mut ctx.accounts.member;
         member.authority = ctx.accounts.authority.key();
         member.policy = ctx.accounts.policy.key();
+        member.policy_version = ctx.accounts.policy.version;
         member.active = true;
-        member.join_timestamp = Clock::get()?.unix_timestamp;
+        member.join_timestamp = now;
+        member.enrollment_id = enrollment_id;
         member.claim_count = 0;
-        member.bump = *ctx.bumps.get("member").unwrap();
-        Ok(())
+        member.open_claims = 0;
+        member.cancelled = false;
+        member.refund_due = 0;
+        member.prior_refund_due = 0;
+        member.last_life_event = 0;
+        member.coverage_mint = ctx.accounts.coverage_mint.key();
+        member.total_premiums_paid = 0;
+        member.credit_premium(
+            ctx.accounts.authority.key(),
+            quote.amount,
+            quote.fee,
+            now.checked_add(PREMIUM_PERIOD).unwrap(),
+        );
+        member.benefit_year_start = now;
+        member.year_paid = 0;
+        member.year_claim_count = 0;
+        member.year_deductible = 0;
+        member.year_out_of_pocket = 0;
+        member.bump = ctx.bumps.member;
+        emit!(PremiumPaid {
+            payer: ctx.accounts.authority.key(),
+            member: member.key(),
+            policy: member.policy,
+            tier: quote.tier,
+            fee_discount: quote.discount,
+            amount: quote.amount,
+            period_start: now,
+            paid_through: member.paid_through,
+        });
+
+        // Mint the proof‑of‑coverage NFT and lock it in the member's wallet.
+        issue_coverage_nft(
+            &ctx.accounts.config,
+            &ctx.accounts.coverage_mint,
+            &ctx.accounts.coverage_token,
+            &ctx.accounts.authority,
+            &ctx.accounts.token_program,
+        )
     }
 
-    /// Pays an additional monthly premium for an existing member.  This
-    /// instruction does not create a member; it merely transfers USDC from
-    /// the user’s account into the premium pool.  It may be used to keep
-    /// coverage active.  Future versions should enforce payment schedules and
-    /// premium due dates.
+    /// Pays an additional monthly premium for an existing member, extending
+    /// `Member.paid_through` by one `PREMIUM_PERIOD`.  Payments made while
+    /// the member is current or within the policy's grace period extend
+    /// coverage from the previous due date.  Once coverage has lapsed, a
+    /// payment reinstates the member from the current time; if the lapse was
+    /// already recorded by `mark_lapsed`, a new proof‑of‑coverage NFT is
+    /// issued.  As in `enroll_member`, the administrative fee is discounted
+    /// when the payer's Stake account is supplied.
+    ///
+    /// If the period being paid for starts on or after the date the member's
+    /// policy version is superseded, the later version in effect at the start
+    /// of the period must be supplied (see `policy_for_period`): the member
+    /// is moved to it and pays its premium.
+    ///
+    /// Coverage may be paid at most one period in advance: a premium is
+    /// accepted only while less than one `PREMIUM_PERIOD` of coverage
+    /// remains.
+    ///
+    /// The payer may be the member or a third party such as an employer or
+    /// family member paying from their own USDC account.  Reinstating a
+    /// member whose lapse has been recorded requires the member to pay
+    /// themselves, because re‑issuing the NFT needs the member's signature.
+    /// Every payment is added to `Member.total_premiums_paid`.
     pub fn pay_premium(ctx: Context<PayPremium>) -> Result<()> {
-        let policy = &ctx.accounts.policy;
-        // Transfer premium from user to premium pool.
+        let now = Clock::get()?.unix_timestamp;
+        require!(!ctx.accounts.member.cancelled, ApolloError::MembershipCancelled);
+        require!(ctx.accounts.member.premium_due(now), ApolloError::PremiumNotDue);
+        let reissue = !ctx.accounts.member.active;
+        if reissue {
+            require_keys_eq!(ctx.accounts.payer.key(), ctx.accounts.member.authority, ApolloError::Unauthorized);
+        }
+        let current = &ctx.accounts.policy;
+        let start = ctx.accounts.member.next_period_start(current, now);
+        let policy = policy_for_period(current, ctx.accounts.next_policy.as_ref(), start)?;
+        let dependents = ctx.accounts.member.dependent_count;
+        let quote = quote_premium(&ctx.accounts.config, policy, dependents, ctx.accounts.stake.as_deref(), now);
+        // Transfer premium from the payer to the premium pool.
         let cpi_accounts = Transfer {
-            from: ctx.accounts.user_usdc_account.to_account_info(),
+            from: ctx.accounts.payer_usdc_account.to_account_info(),
             to: ctx.accounts.premium_pool.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), quote.amount)?;
+        let cfg = &mut ctx.accounts.config;
+        cfg.protocol_fees = cfg.protocol_fees.checked_add(quote.fee).unwrap();
+
+        let member = &mut ctx.accounts.member;
+        member.policy = policy.key();
+        member.policy_version = policy.version;
+        member.credit_premium(
+            ctx.accounts.payer.key(),
+            quote.amount,
+            quote.fee,
+            start.checked_add(PREMIUM_PERIOD).unwrap(),
+        );
+        member.active = true;
+        emit!(PremiumPaid {
+            payer: ctx.accounts.payer.key(),
+            member: member.key(),
+            policy: policy.key(),
+            tier: quote.tier,
+            fee_discount: quote.discount,
+            amount: quote.amount,
+            period_start: start,
+            paid_through: member.paid_through,
+        });
+        if reissue {
+            issue_coverage_nft(
+                &ctx.accounts.config,
+                &ctx.accounts.coverage_mint,
+                &ctx.accounts.coverage_token,
+                &ctx.accounts.payer,
+                &ctx.accounts.token_program,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Marks a member whose coverage has lapsed as inactive.  Anyone may
+    /// call this once the member's `paid_through` plus the policy's grace
+    /// period has passed.  The member's proof‑of‑coverage NFT is thawed and
+    /// burned so that it no longer attests coverage.
+    pub fn mark_lapsed(ctx: Context<MarkLapsed>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let member = &mut ctx.accounts.member;
+        require!(member.active, ApolloError::InvalidParameter);
+        require!(!member.is_covered(&ctx.accounts.policy, now), ApolloError::CoverageStillActive);
+        member.active = false;
+        revoke_coverage_nft(
+            &ctx.accounts.config,
+            &ctx.accounts.coverage_mint,
+            &ctx.accounts.coverage_token,
+            &ctx.accounts.token_program,
+        )
+    }
+
+    /// Moves a member to a different policy.  Allowed during the open
+    /// enrollment window set in the config, or at any time after a
+    /// qualifying life event.  A life event is shown either by a co‑signature
+    /// from the config's life event attestor or by a fresh report on the
+    /// member from the `LifeEvent` oracle feed; each report allows one
+    /// change.
+    /// The target policy must be open for enrollment and the member's
+    /// coverage must be current.  The difference between the two monthly
+    /// premiums, prorated over the rest of the paid period, is charged to the
+    /// member or refunded from the premium pool to whoever paid that period.
+    /// The administrative fee part of a refund is capped at `protocol_fees`.
+    /// The Member account, and so its claim history and benefit‑year
+    /// accumulators, is kept.  Open claims are adjudicated under the member's
+    /// current policy, so no change is allowed while any remain.
+    pub fn change_policy(ctx: Context<ChangePolicy>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let cfg = &ctx.accounts.config;
+        let in_window = cfg.open_enrollment_start <= now && now < cfg.open_enrollment_end;
+        let attested = match &ctx.accounts.attestor {
+            Some(attestor) => {
+                require!(cfg.life_event_attestor != Pubkey::default(), ApolloError::Unauthorized);
+                require_keys_eq!(attestor.key(), cfg.life_event_attestor, ApolloError::Unauthorized);
+                true
+            }
+            None => false,
+        };
+        let reported = match (&ctx.accounts.life_event_feed, &ctx.accounts.life_event_report) {
+            (Some(feed), Some(report)) => {
+                let member = ctx.accounts.member.key();
+                require!(report.observed_at > ctx.accounts.member.last_life_event, ApolloError::StaleOracleReport);
+                read_oracle_report(feed, report, FeedKind::LifeEvent, &member, now)? != 0
+            }
+            _ => false,
+        };
+        require!(in_window || attested || reported, ApolloError::EnrollmentClosed);
+        let old_policy = &ctx.accounts.policy;
+        let new_policy = &ctx.accounts.new_policy;
+        require_keys_neq!(old_policy.key(), new_policy.key(), ApolloError::InvalidParameter);
+        require!(ctx.accounts.member.is_covered(old_policy, now), ApolloError::CoverageLapsed);
+        require!(ctx.accounts.member.employer_group == Pubkey::default(), ApolloError::InEmployerGroup);
+        require!(ctx.accounts.member.open_claims == 0, ApolloError::ClaimsPending);
+
+        // Prorate the premium difference over the unused part of the period.
+        let stake = ctx.accounts.stake.as_deref();
+        let dependents = ctx.accounts.member.dependent_count;
+        let old_quote = quote_premium(cfg, old_policy, dependents, stake, now);
+        let new_quote = quote_premium(cfg, new_policy, dependents, stake, now);
+        let (premium_delta, fee_delta) = ctx.accounts.member.reprice_period(&old_quote, &new_quote, now);
+        // Reviewer payouts may have drawn `protocol_fees` down, so the fee
+        // part of a refund is capped at what is left; the rest stays in the
+        // pool.
+        let fee_applied = ctx.accounts.config.apply_fee_delta(fee_delta);
+        let premium_delta = premium_delta - fee_delta + fee_applied;
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        if premium_delta > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.user_usdc_account.to_account_info(),
+                to: ctx.accounts.premium_pool.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            token::transfer(CpiContext::new(cpi_program, cpi_accounts), premium_delta as u64)?;
+        } else if premium_delta < 0 {
+            let refund = premium_delta.unsigned_abs() as u64;
+            require!(ctx.accounts.premium_pool.amount >= refund, ApolloError::InsufficientPoolBalance);
+            let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[ctx.accounts.config.bump]]];
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.premium_pool.to_account_info(),
+                to: ctx.accounts.refund_usdc_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            };
+            token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), refund)?;
+        }
+
+        let member = &mut ctx.accounts.member;
+        member.policy = new_policy.key();
+        member.policy_version = new_policy.version;
+        if !in_window && !attested {
+            member.last_life_event = ctx.accounts.life_event_report.as_ref().unwrap().observed_at;
+        }
+        emit!(PolicyChanged {
+            member: member.key(),
+            old_policy: old_policy.key(),
+            new_policy: new_policy.key(),
+            life_event: !in_window,
+            premium_delta: premium_delta as i64,
+        });
+        Ok(())
+    }
+
+    /// Adds a dependent to the caller's membership.  The dependent is
+    /// identified by a hash of an off‑chain record.  Each dependent adds the
+    /// policy's `dependent_premium` to every future monthly premium; the
+    /// share for the rest of the current paid period is charged now,
+    /// prorated.  Not allowed while the member is in an employer group or
+    /// the current period was paid by a third party (see
+    /// `Member::check_dependent_change`).
+    pub fn add_dependent(ctx: Context<AddDependent>, identity_hash: [u8; 32]) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let member = &mut ctx.accounts.member;
+        require!(member.is_covered(&ctx.accounts.policy, now), ApolloError::CoverageLapsed);
+        member.check_dependent_change()?;
+        require!(member.dependent_count < MAX_DEPENDENTS, ApolloError::TooManyDependents);
+        let remaining = (member.paid_through - now).clamp(0, PREMIUM_PERIOD) as u128;
+        let amount = (ctx.accounts.policy.dependent_premium as u128 * remaining / PREMIUM_PERIOD as u128) as u64;
+        if amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.user_usdc_account.to_account_info(),
+                to: ctx.accounts.premium_pool.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+            member.total_premiums_paid = member.total_premiums_paid.checked_add(amount).unwrap();
+        }
+        let dependent = &mut ctx.accounts.dependent;
+        dependent.member = member.key();
+        dependent.id = member.next_dependent_id;
+        dependent.identity_hash = identity_hash;
+        dependent.added_at = now;
+        dependent.benefit_year_start = member.benefit_year_start;
+        dependent.year_deductible = 0;
+        dependent.open_claims = 0;
+        dependent.bump = ctx.bumps.dependent;
+        member.next_dependent_id = member.next_dependent_id.checked_add(1).unwrap();
+        member.dependent_count += 1;
+        member.last_premium = member.last_premium.checked_add(ctx.accounts.policy.dependent_premium).unwrap();
+        Ok(())
+    }
+
+    /// Removes a dependent from the caller's membership and closes the
+    /// Dependent account, returning its rent to the subscriber.  The
+    /// dependent premium for the unused part of the paid period is refunded
+    /// from the premium pool to the member.  Every claim filed for the
+    /// dependent must have been paid or denied first, because open claims
+    /// could no longer be paid afterwards.  As with `add_dependent`, not
+    /// allowed while the member is in an employer group or the current period
+    /// was paid by a third party.
+    pub fn remove_dependent(ctx: Context<RemoveDependent>) -> Result<()> {
+        require!(ctx.accounts.dependent.open_claims == 0, ApolloError::ClaimsPending);
+        let now = Clock::get()?.unix_timestamp;
+        let member = &mut ctx.accounts.member;
+        member.check_dependent_change()?;
+        let dependent_premium = ctx.accounts.policy.dependent_premium;
+        let remaining = (member.paid_through - now).clamp(0, PREMIUM_PERIOD) as u128;
+        let refund = ((dependent_premium as u128 * remaining / PREMIUM_PERIOD as u128) as u64)
+            .min(member.last_premium)
+            .min(member.total_premiums_paid);
+        member.dependent_count -= 1;
+        member.last_premium = member.last_premium.saturating_sub(dependent_premium);
+        member.total_premiums_paid -= refund;
+        if refund > 0 {
+            require!(ctx.accounts.premium_pool.amount >= refund, ApolloError::InsufficientPoolBalance);
+            let cfg = &ctx.accounts.config;
+            let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.premium_pool.to_account_info(),
+                to: ctx.accounts.refund_usdc_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), refund)?;
+        }
+        Ok(())
+    }
+
+    /// Creates an employer group sponsored by the caller under `policy`.  The
+    /// sponsor pays `sponsor_share_bps` of each enrolled employee's premium;
+    /// the rest is the employee's contribution.
+    pub fn create_employer_group(ctx: Context<CreateEmployerGroup>, sponsor_share_bps: u16) -> Result<()> {
+        require!(sponsor_share_bps as u64 <= BPS_DENOMINATOR, ApolloError::InvalidParameter);
+        let group = &mut ctx.accounts.group;
+        group.sponsor = ctx.accounts.sponsor.key();
+        group.policy = ctx.accounts.policy.key();
+        group.sponsor_share_bps = sponsor_share_bps;
+        group.members = Vec::new();
+        group.paid_through = 0;
+        group.bump = ctx.bumps.group;
+        Ok(())
+    }
+
+    /// Adds a member to an employer group's roster.  Both the sponsor and
+    /// the member must sign, and the member must already be enrolled in the
+    /// group's policy.  From the next `pay_group_premium` on, the member's
+    /// premiums are paid through the group.
+    pub fn join_employer_group(ctx: Context<JoinEmployerGroup>) -> Result<()> {
+        let group = &mut ctx.accounts.group;
+        let member = &mut ctx.accounts.member;
+        require_keys_eq!(member.policy, group.policy, ApolloError::InvalidParameter);
+        require!(member.active, ApolloError::CoverageLapsed);
+        require!(member.employer_group == Pubkey::default(), ApolloError::InEmployerGroup);
+        require!(group.members.len() < MAX_GROUP_MEMBERS, ApolloError::EmployerGroupFull);
+        group.members.push(member.key());
+        member.employer_group = group.key();
+        Ok(())
+    }
+
+    /// Removes a member from an employer group's roster.  Either the sponsor
+    /// or the member may call this.  The member keeps the coverage already
+    /// paid for and pays future premiums individually.
+    pub fn leave_employer_group(ctx: Context<LeaveEmployerGroup>) -> Result<()> {
+        let group = &mut ctx.accounts.group;
+        let member = &mut ctx.accounts.member;
+        let caller = ctx.accounts.signer.key();
+        require!(caller == group.sponsor || caller == member.authority, ApolloError::Unauthorized);
+        let index = group
+            .members
+            .iter()
+            .position(|m| *m == member.key())
+            .ok_or_else(|| error!(ApolloError::InvalidParameter))?;
+        group.members.swap_remove(index);
+        member.employer_group = Pubkey::default();
+        Ok(())
+    }
+
+    /// Pays one period of premiums for every member on an employer group's
+    /// roster.  The sponsor's share of all premiums is debited from the
+    /// sponsor's USDC account in a single transfer.  Each employee's
+    /// contribution is debited from their USDC account, on which they must
+    /// have approved the config PDA as delegate.  The config PDA is the
+    /// delegate for every group, so employees should approve no more than
+    /// their contribution; the program only draws on the delegation here, for
+    /// the group on whose roster the employee is.  Each charged member's
+    /// coverage is extended by one period from their own `paid_through`.
+    /// Members already paid through the group's new `paid_through` or a full
+    /// period in advance (for example after prepaying individually), members
+    /// whose lapse has been
+    /// recorded and members no longer on the group's policy are skipped and
+    /// not charged; lapsed members must be reinstated individually with
+    /// `pay_premium`.  The group may be paid at most one period in advance.
+    /// Staking discounts do not apply to group premiums.
+    ///
+    /// As in `pay_premium`, once the group's policy version is superseded
+    /// the next version must be supplied.  Each member is charged, and moved
+    /// to, the version in effect at the start of their period, and the group
+    /// moves to the version in effect at the start of its period.
+    ///
+    /// `remaining_accounts` must hold, for each roster entry in order, the
+    /// Member account followed by the employee's USDC token account.
+    pub fn pay_group_premium<'info>(ctx: Context<'_, '_, 'info, 'info, PayGroupPremium<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let group = &ctx.accounts.group;
+        require!(group.paid_through < now.checked_add(PREMIUM_PERIOD).unwrap(), ApolloError::GroupPremiumNotDue);
+        require!(ctx.remaining_accounts.len() == group.members.len() * 2, ApolloError::InvalidParameter);
+        let period_start = group.paid_through.max(now);
+        let paid_through = period_start.checked_add(PREMIUM_PERIOD).unwrap();
+        let current = &ctx.accounts.policy;
+        let next = ctx.accounts.next_policy.as_ref();
+        let group_policy = policy_for_period(current, next, period_start)?.key();
+        let cfg = &ctx.accounts.config;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let mut sponsor_total: u64 = 0;
+        let mut fees: u64 = 0;
+        for (i, pair) in ctx.remaining_accounts.chunks(2).enumerate() {
+            let mut member = Account::<Member>::try_from(&pair[0])?;
+            require_keys_eq!(member.key(), group.members[i], ApolloError::InvalidParameter);
+            let on_next = next.is_some_and(|next| member.policy == next.key());
+            if !member.active
+                || !(member.policy == current.key() || on_next)
+                || member.paid_through >= paid_through
+                || !member.premium_due(now)
+            {
+                continue;
+            }
+            let member_start = member.paid_through.max(now);
+            let policy = match next {
+                Some(next) if on_next => next,
+                _ => policy_for_period(current, next, member_start)?,
+            };
+            let quote = quote_premium(cfg, policy, member.dependent_count, None, now);
+            let GroupShares { sponsor_share, sponsor_fee, employee_share } = group.split(&quote);
+            if employee_share > 0 {
+                let employee_usdc = Account::<TokenAccount>::try_from(&pair[1])?;
+                require!(employee_usdc.mint == cfg.usdc_mint, ApolloError::InvalidParameter);
+                require_keys_eq!(employee_usdc.owner, member.authority, ApolloError::Unauthorized);
+                let cpi_accounts = Transfer {
+                    from: pair[1].clone(),
+                    to: ctx.accounts.premium_pool.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                };
+                token::transfer(
+                    CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds),
+                    employee_share,
+                )?;
+            }
+            sponsor_total = sponsor_total.checked_add(sponsor_share).unwrap();
+            fees = fees.checked_add(quote.fee).unwrap();
+            member.policy = policy.key();
+            member.policy_version = policy.version;
+            // Only the sponsor's share is refundable, and only to the sponsor.
+            member.credit_premium(
+                group.sponsor,
+                sponsor_share,
+                sponsor_fee,
+                member_start.checked_add(PREMIUM_PERIOD).unwrap(),
+            );
+            member.total_premiums_paid = member.total_premiums_paid.checked_add(employee_share).unwrap();
+            emit!(PremiumPaid {
+                payer: ctx.accounts.sponsor.key(),
+                member: member.key(),
+                policy: policy.key(),
+                tier: None,
+                fee_discount: 0,
+                amount: quote.amount,
+                period_start: member_start,
+                paid_through: member.paid_through,
+            });
+            member.exit(ctx.program_id)?;
+        }
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.sponsor_usdc_account.to_account_info(),
+            to: ctx.accounts.premium_pool.to_account_info(),
+            authority: ctx.accounts.sponsor.to_account_info(),
         };
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), sponsor_total)?;
+        let cfg = &mut ctx.accounts.config;
+        cfg.protocol_fees = cfg.protocol_fees.checked_add(fees).unwrap();
+        let group = &mut ctx.accounts.group;
+        group.policy = group_policy;
+        group.paid_through = paid_through;
+        Ok(())
+    }
+
+    /// Cancels the caller's membership.  Coverage ends immediately and the
+    /// proof‑of‑coverage NFT is burned.  The premium for the unused part of
+    /// the paid periods is recorded as refunds, prorated from the premium
+    /// charged for each period and capped at the premiums paid so far (see
+    /// `Member::cancellation_refund`); the administrative fee share of them
+    /// is taken back out of accrued protocol fees.  Each period's refund goes
+    /// to whoever paid it.
+    /// Claims already submitted are still adjudicated.  The member must first
+    /// leave any employer group and remove all dependents.  Call
+    /// `close_membership` to pay the refund once no claims are pending.
+    pub fn cancel_membership(ctx: Context<CancelMembership>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let member = &ctx.accounts.member;
+        require!(!member.cancelled, ApolloError::MembershipCancelled);
+        require!(member.employer_group == Pubkey::default(), ApolloError::InEmployerGroup);
+        require!(member.dependent_count == 0, ApolloError::DependentsRemaining);
+        let refund = member.cancellation_refund(&ctx.accounts.policy, now);
+        let cfg = &mut ctx.accounts.config;
+        cfg.protocol_fees = cfg.protocol_fees.saturating_sub(refund.fees);
+        if member.active {
+            revoke_coverage_nft(
+                &ctx.accounts.config,
+                &ctx.accounts.coverage_mint,
+                &ctx.accounts.coverage_token,
+                &ctx.accounts.token_program,
+            )?;
+        }
+        let member = &mut ctx.accounts.member;
+        member.active = false;
+        member.cancelled = true;
+        member.paid_through = member.paid_through.min(now);
+        member.refund_due = refund.last;
+        member.prior_refund_due = refund.prior;
+        Ok(())
+    }
+
+    /// Pays a cancelled member's premium refunds from the premium pool to the
+    /// payers of the last premium period and, if part of it was unused, the
+    /// period before it, and closes the Member account, returning its rent
+    /// to the member.  Only allowed once every claim the member submitted
+    /// has been paid or denied.  The wallet may enroll again afterwards.
+    pub fn close_membership(ctx: Context<CloseMembership>) -> Result<()> {
+        let member = &ctx.accounts.member;
+        require!(member.cancelled, ApolloError::InvalidParameter);
+        require!(member.open_claims == 0, ApolloError::ClaimsPending);
+        let (refund, prior_refund) = (member.refund_due, member.prior_refund_due);
+        require!(
+            ctx.accounts.premium_pool.amount >= refund.checked_add(prior_refund).unwrap(),
+            ApolloError::InsufficientPoolBalance
+        );
+        let cfg = &ctx.accounts.config;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        token::transfer(CpiContext::new(cpi_program, cpi_accounts), policy.monthly_premium)?;
+        if refund > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.premium_pool.to_account_info(),
+                to: ctx.accounts.refund_usdc_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            };
+            token::transfer(CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds), refund)?;
+        }
+        if prior_refund > 0 {
+            let prior_refund_usdc_account =
+                ctx.accounts.prior_refund_usdc_account.as_ref().ok_or_else(|| error!(ApolloError::InvalidPayee))?;
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.premium_pool.to_account_info(),
+                to: prior_refund_usdc_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            };
+            token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), prior_refund)?;
+        }
         Ok(())
     }
 
     /// Stakes APH tokens into the capital pool.  This provides additional
-    /// underwriting capital for claims and, in future versions, qualifies the
-    /// staker for discounts and governance power.  The staked amount is
-    /// recorded in a Stake account associated with the user.  The protocol
+    /// underwriting capital for claims, grants governance voting power and
+    /// qualifies the staker for premium fee discounts.  The staked
+    /// amount is recorded in a Stake account associated with the user along
+    /// with its voting power checkpoints and TWAB history.  The protocol
     /// authority is not involved in staking.
     pub fn stake_aph(ctx: Context<StakeAPH>, amount: u64) -> Result<()> {
         require!(amount > 0, ApolloError::InvalidParameter);
@@ -136,18 +1009,22 @@ pub mod apollo_core {
             // Ensure the stake account belongs to the same authority.
             require_keys_eq!(stake.authority, ctx.accounts.authority.key(), ApolloError::Unauthorized);
         }
-        stake.amount = stake.amount.checked_add(amount).unwrap();
-        stake.start_timestamp = Clock::get()?.unix_timestamp;
-        stake.bump = *ctx.bumps.get("stake").unwrap();
+        let clock = Clock::get()?;
+        let new_amount = stake.amount.checked_add(amount).unwrap();
+        stake.set_amount(new_amount, &clock);
+        stake.start_timestamp = clock.unix_timestamp;
+        stake.bump = ctx.bumps.stake;
         Ok(())
     }
 
     /// Unstakes APH tokens and returns them to the user.  This instruction
     /// transfers the full staked amount back to the user and resets the
-    /// stake account.  It does not enforce a lockup period in v1.
+    /// stake account.  It does not enforce a lockup period in v1, but a
+    /// registered reviewer must deregister first.
     pub fn unstake_aph(ctx: Context<UnstakeAPH>) -> Result<()> {
         let amount = ctx.accounts.stake.amount;
         require!(amount > 0, ApolloError::InvalidParameter);
+        require!(!ctx.accounts.stake.reviewer, ApolloError::StakeLocked);
         // Transfer APH from capital pool to user using config as signer (the
         // authority of the capital pool token account).  The seed bump must
         // correspond to the config PDA.
@@ -161,49 +1038,273 @@ pub mod apollo_core {
         let cpi_program = ctx.accounts.token_program.to_account_info();
         token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), amount)?;
         // Reset stake.
-        ctx.accounts.stake.amount = 0;
+        ctx.accounts.stake.set_amount(0, &Clock::get()?);
+        Ok(())
+    }
+
+    /// Returns the time‑weighted average staked balance of a Stake account
+    /// over `[start, end]` (unix timestamps).  The window must end no later
+    /// than the current time and after the oldest retained TWAB observation;
+    /// a window beginning earlier is measured from that observation.  The
+    /// result is returned through the transaction's
+    /// return data so that clients can simulate this instruction as a view.
+    pub fn get_twab(ctx: Context<GetTwab>, start: i64, end: i64) -> Result<u64> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(start < end && end <= now, ApolloError::InvalidParameter);
+        ctx.accounts.stake.twab(start, end).ok_or_else(|| error!(ApolloError::TwabWindowUnavailable))
+    }
+
+    /// Executes a passed `ConfigureOracleFeed` proposal, creating the feed of
+    /// the given kind or replacing its signer set, threshold and staleness
+    /// limit.  Anyone may call this.  Reports already posted to the feed
+    /// remain readable until they go stale.
+    pub fn configure_oracle_feed(ctx: Context<ConfigureOracleFeed>, kind: FeedKind) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.require_executable(now)?;
+        let feed = &mut ctx.accounts.feed;
+        match proposal.action {
+            ProposalAction::ConfigureOracleFeed { kind: target, signers, signer_count, threshold, max_staleness } => {
+                require!(target == kind, ApolloError::InvalidParameter);
+                feed.signers = signers;
+                feed.signer_count = signer_count;
+                feed.threshold = threshold;
+                feed.max_staleness = max_staleness;
+            }
+            _ => return err!(ApolloError::InvalidProposalAction),
+        }
+        feed.kind = kind;
+        feed.bump = ctx.bumps.feed;
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = now;
+        Ok(())
+    }
+
+    /// Posts an oracle report on `subject` to a feed.  Anyone may relay a
+    /// report, but the transaction must also contain Ed25519 program
+    /// instructions verifying signatures over the report message (see
+    /// `OracleReport::message`) from at least `threshold` distinct members of
+    /// the feed's signer set.  The observation time must be within the
+    /// feed's `max_staleness` of now and newer than the report it replaces,
+    /// so old reports cannot be replayed.
+    pub fn post_oracle_report(
+        ctx: Context<PostOracleReport>,
+        subject: Pubkey,
+        value: u64,
+        data_hash: [u8; 32],
+        observed_at: i64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let feed = &ctx.accounts.feed;
+        let report = &mut ctx.accounts.report;
+        let message = OracleReport::message(&feed.key(), &subject, value, &data_hash, observed_at);
+        let signatures = count_oracle_signatures(feed, &ctx.accounts.instructions, &message)?;
+        feed.accept_report(observed_at, report.observed_at, signatures, now)?;
+        report.feed = feed.key();
+        report.subject = subject;
+        report.value = value;
+        report.data_hash = data_hash;
+        report.observed_at = observed_at;
+        report.bump = ctx.bumps.report;
+        Ok(())
+    }
+
+    /// Registers the caller as a healthcare provider.  The provider supplies
+    /// a hash of their NPI, a specialty code and the USDC account that
+    /// receives direct payouts.  Providers start unverified; their
+    /// credentials are attested off chain and the verified flag is set by
+    /// the provider attestor (`attest_provider`) or by governance
+    /// (`verify_provider`).
+    pub fn register_provider(ctx: Context<RegisterProvider>, npi_hash: [u8; 32], specialty: u16) -> Result<()> {
+        let provider = &mut ctx.accounts.provider;
+        provider.authority = ctx.accounts.authority.key();
+        provider.npi_hash = npi_hash;
+        provider.specialty = specialty;
+        provider.usdc_account = ctx.accounts.usdc_account.key();
+        provider.verified = false;
+        provider.registered_at = Clock::get()?.unix_timestamp;
+        provider.bump = ctx.bumps.provider;
+        Ok(())
+    }
+
+    /// Sets or clears a provider's verified flag.  Only the provider
+    /// attestor configured by governance may call this.
+    pub fn attest_provider(ctx: Context<AttestProvider>, verified: bool) -> Result<()> {
+        let provider = &mut ctx.accounts.provider;
+        provider.verified = verified;
+        Ok(())
+    }
+
+    /// Executes a passed `VerifyProvider` proposal, setting or clearing the
+    /// provider's verified flag.  Anyone may call this.
+    pub fn verify_provider(ctx: Context<VerifyProvider>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.require_executable(now)?;
+        let (target, verified) = match proposal.action {
+            ProposalAction::VerifyProvider { provider, verified } => (provider, verified),
+            _ => return err!(ApolloError::InvalidProposalAction),
+        };
+        let provider = &mut ctx.accounts.provider;
+        require_keys_eq!(provider.key(), target, ApolloError::InvalidParameter);
+        provider.verified = verified;
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = now;
+        Ok(())
+    }
+
+    /// Creates or replaces the fee schedule for a procedure category.  Only
+    /// the price oracle configured by governance may call this.  The
+    /// schedule holds the amount the plan allows for the category and the
+    /// market's billed amounts at each of `FEE_SCHEDULE_PERCENTILES`, which
+    /// must not decrease.
+    pub fn set_fee_schedule(
+        ctx: Context<SetFeeSchedule>,
+        procedure_category: u16,
+        allowed_amount: u64,
+        billed_percentiles: [u64; FEE_PERCENTILES],
+    ) -> Result<()> {
+        require!(procedure_category != 0, ApolloError::InvalidParameter);
+        require!(allowed_amount > 0, ApolloError::InvalidParameter);
+        require!(billed_percentiles.windows(2).all(|w| w[0] <= w[1]), ApolloError::InvalidParameter);
+        let schedule = &mut ctx.accounts.fee_schedule;
+        schedule.procedure_category = procedure_category;
+        schedule.allowed_amount = allowed_amount;
+        schedule.billed_percentiles = billed_percentiles;
+        schedule.updated_at = Clock::get()?.unix_timestamp;
+        schedule.bump = ctx.bumps.fee_schedule;
         Ok(())
     }
 
-    /// Submits a claim for reimbursement.  The claim amount (in USDC) and a
-    /// cryptographic hash of the off‑chain documentation are recorded.  If the
-    /// amount is below the fast claim threshold specified in the config, the
-    /// claim is automatically approved and paid out from the premium pool to
-    /// the user’s USDC account.  Otherwise the claim is recorded with
-    /// `NeedsReview` status and must be manually approved by the authority.
+    /// Submits a claim for reimbursement.  The claimant's coverage must not
+    /// have lapsed and they must hold their proof‑of‑coverage NFT.  The claim
+    /// amount (in USDC) and a cryptographic hash of the off‑chain
+    /// documentation are recorded.  The amount may not exceed the policy's
+    /// coverage limit, and the member must have benefits left under the
+    /// policy's annual maximum.  If the amount is below the fast claim
+    /// threshold specified in the config, the claim is automatically approved
+    /// and paid out from the premium pool to the payee after applying the
+    /// policy's cost sharing (see `Member::apply_cost_sharing`).  Otherwise
+    /// the claim is recorded with `NeedsReview` status and awaits a review
+    /// panel or the claims authority.
+    ///
+    /// If the claimant passes a registered Provider, the claim is paid
+    /// directly to the provider's USDC account, and it is in network if the
+    /// provider is verified or a fresh report from the `ProviderCredential`
+    /// oracle feed attests the provider's credentials.  Otherwise the claim
+    /// reimburses the claimant's own USDC account and is out of network.
+    /// The payee is fixed at submission, so later approvals always pay the
+    /// same account.
+    ///
+    /// The claimant may attach structured, non‑PHI metadata describing the
+    /// service (see `ClaimMetadata`).  If given, the service dates must fall
+    /// between the start of the patient's coverage and now, and any provider
+    /// named in it must match the Provider passed.  Once governance has
+    /// appointed a price oracle, metadata is required.  Claims with metadata
+    /// must pass the FeeSchedule address for its procedure category.  If a
+    /// schedule exists there, its allowed amount caps every later payout of
+    /// the claim, and a billed amount above the schedule's
+    /// `Config.price_flag_percentile` flags the claim for review even if it
+    /// is under the fast claim threshold.  Once a price oracle is appointed,
+    /// a claim whose category has no schedule is flagged as well (see
+    /// `price_claim`).
     pub fn submit_claim(
         ctx: Context<SubmitClaim>,
         amount: u64,
         offchain_hash: [u8; 32],
+        metadata: Option<ClaimMetadata>,
     ) -> Result<()> {
         require!(amount > 0, ApolloError::InvalidParameter);
+        let now = Clock::get()?.unix_timestamp;
+        let policy = &ctx.accounts.policy;
+        let member = &mut ctx.accounts.member;
+        require!(member.is_covered(policy, now), ApolloError::CoverageLapsed);
+        require!(
+            metadata.is_some() || ctx.accounts.config.price_oracle == Pubkey::default(),
+            ApolloError::FeeScheduleRequired
+        );
+        if let Some(metadata) = &metadata {
+            let covered_from = match ctx.accounts.dependent.as_deref() {
+                Some(dependent) => dependent.added_at,
+                None => member.join_timestamp,
+            };
+            metadata.validate(covered_from, now)?;
+        }
+        let payee = ctx.accounts.payee_usdc_account.key();
+        let (provider, in_network) = match &ctx.accounts.provider {
+            Some(provider) => {
+                require_keys_eq!(payee, provider.usdc_account, ApolloError::InvalidPayee);
+                if let Some(named) = metadata.and_then(|m| m.provider) {
+                    require_keys_eq!(named, provider.key(), ApolloError::InvalidParameter);
+                }
+                let credentialed = match (&ctx.accounts.credential_feed, &ctx.accounts.credential_report) {
+                    (Some(feed), Some(report)) => {
+                        read_oracle_report(feed, report, FeedKind::ProviderCredential, &provider.key(), now)? != 0
+                    }
+                    _ => false,
+                };
+                (Some(provider.key()), provider.verified || credentialed)
+            }
+            None => {
+                let owner = ctx.accounts.payee_usdc_account.owner;
+                require_keys_eq!(owner, ctx.accounts.authority.key(), ApolloError::InvalidPayee);
+                (None, false)
+            }
+        };
+        member.admit_claim(policy, amount, now)?;
         // Record claim.
         let claim = &mut ctx.accounts.claim;
         claim.member = ctx.accounts.member.key();
+        claim.enrollment_id = ctx.accounts.member.enrollment_id;
         claim.amount = amount;
         claim.hash = offchain_hash;
-        claim.submitted_at = Clock::get()?.unix_timestamp;
+        claim.dependent = ctx.accounts.dependent.as_ref().map(|d| d.key());
+        claim.metadata = metadata;
+        claim.provider = provider;
+        claim.in_network = in_network;
+        claim.payee = payee;
+        let cfg = &ctx.accounts.config;
+        let mut schedule = None;
+        if let Some(metadata) = &metadata {
+            let info = ctx.accounts.fee_schedule.as_ref().ok_or_else(|| error!(ApolloError::FeeScheduleRequired))?;
+            let category = metadata.procedure_category.to_le_bytes();
+            let (expected, _) = Pubkey::find_program_address(&[b"fee_schedule", &category], ctx.program_id);
+            require_keys_eq!(info.key(), expected, ApolloError::FeeScheduleRequired);
+            // Categories without a schedule leave the address unallocated.
+            if info.owner == ctx.program_id {
+                schedule = Some(FeeSchedule::try_deserialize(&mut &info.try_borrow_data()?[..])?);
+            }
+        }
+        let (allowed_amount, price_flagged) = price_claim(cfg, schedule.as_ref(), amount);
+        claim.allowed_amount = allowed_amount;
+        claim.price_flagged = price_flagged;
+        claim.submitted_at = now;
         claim.updated_at = claim.submitted_at;
-        claim.bump = *ctx.bumps.get("claim").unwrap();
+        claim.bump = ctx.bumps.claim;
 
-        let cfg = &ctx.accounts.config;
         // Determine whether claim is small enough for automatic approval.
-        if amount <= cfg.fast_claim_threshold {
+        if amount <= cfg.fast_claim_threshold && !claim.price_flagged {
             // Perform auto‑approval and payment if funds are available.
+            let dependent = ctx.accounts.dependent.as_deref_mut();
+            let (billed, reason) = claim.apply_fee_schedule(amount, REASON_NONE);
+            let amount =
+                ctx.accounts.member.apply_cost_sharing(&ctx.accounts.policy, now, billed, claim.in_network, dependent);
+            claim.record_approval(billed, amount, reason);
             let pool_balance = ctx.accounts.premium_pool.amount;
             require!(pool_balance >= amount, ApolloError::InsufficientPoolBalance);
             // Transfer USDC from premium pool to user's account using config as signer.
             let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
             let cpi_accounts = Transfer {
                 from: ctx.accounts.premium_pool.to_account_info(),
-                to: ctx.accounts.user_usdc_account.to_account_info(),
+                to: ctx.accounts.payee_usdc_account.to_account_info(),
                 authority: ctx.accounts.config.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), amount)?;
-            claim.status = ClaimStatus::Paid;
         } else {
             claim.status = ClaimStatus::NeedsReview;
+            claim.review_seed_slot = Clock::get()?.slot.checked_add(REVIEW_SEED_DELAY).unwrap();
+            ctx.accounts.member.open_claim(ctx.accounts.dependent.as_deref_mut());
         }
         // Increment member's claim count.
         let member = &mut ctx.accounts.member;
@@ -212,297 +1313,3574 @@ pub mod apollo_core {
     }
 
     /// Approves a pending claim and pays it out.  This instruction may only
-    /// be called by the protocol authority.  It is intended for claims whose
-    /// amount exceeds the fast claim threshold.  After approval, USDC is
-    /// transferred from the premium pool to the claimant’s account.
-    pub fn approve_claim(ctx: Context<ApproveClaim>) -> Result<()> {
+    /// be called by the claims authority, and only for claims whose review
+    /// panel escalated them, or claims awaiting review while the reviewer
+    /// registry is too small to draw a panel for them.  A claim under or
+    /// awaiting panel review cannot be settled here.  The authority may allow
+    /// less than the billed amount; the allowed amount and a reason code
+    /// explaining the adjustment are recorded on the claim, which is marked
+    /// `PartiallyApproved` instead of `Paid`.  The allowed amount is further
+    /// capped by the fee schedule checked at submission, if any.  After
+    /// approval, the policy's cost sharing is applied to the allowed amount
+    /// and the plan's share is transferred in USDC from the premium pool to
+    /// the payee.  If the member routed the claim through the fiat bridge
+    /// (`request_fiat_payout`), the payee is the request's escrow and the
+    /// payout reaches the member through an off‑ramp operator.
+    pub fn approve_claim(ctx: Context<ApproveClaim>, approved_amount: u64, reason_code: u16) -> Result<()> {
         // Ensure caller is the authority.
         require_keys_eq!(ctx.accounts.authority.key(), ctx.accounts.config.authority, ApolloError::Unauthorized);
+        let now = Clock::get()?.unix_timestamp;
         let claim = &mut ctx.accounts.claim;
-        // Only allow approving claims that are pending review.
-        require!(claim.status == ClaimStatus::NeedsReview, ApolloError::InvalidClaimStatus);
+        let claimant = ctx.accounts.member.authority;
+        require!(
+            claim.awaits_authority(&ctx.accounts.registry, &claimant, ctx.program_id)?,
+            ApolloError::InvalidClaimStatus
+        );
+        require!(approved_amount > 0, ApolloError::InvalidParameter);
+        require!(approved_amount <= claim.amount, ApolloError::ApprovedAmountExceedsClaim);
+        let (approved_amount, reason_code) = claim.apply_fee_schedule(approved_amount, reason_code);
         // Pay the claim.
-        let amount = claim.amount;
+        require!(ctx.accounts.dependent.as_ref().map(|d| d.key()) == claim.dependent, ApolloError::InvalidParameter);
+        let dependent = ctx.accounts.dependent.as_deref_mut();
+        let amount = ctx.accounts.member.apply_cost_sharing(
+            &ctx.accounts.policy,
+            now,
+            approved_amount,
+            claim.in_network,
+            dependent,
+        );
+        claim.record_approval(approved_amount, amount, reason_code);
         let pool_balance = ctx.accounts.premium_pool.amount;
         require!(pool_balance >= amount, ApolloError::InsufficientPoolBalance);
         let cfg = &ctx.accounts.config;
         let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
         let cpi_accounts = Transfer {
             from: ctx.accounts.premium_pool.to_account_info(),
-            to: ctx.accounts.user_usdc_account.to_account_info(),
+            to: ctx.accounts.payee_usdc_account.to_account_info(),
             authority: ctx.accounts.config.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), amount)?;
-        claim.status = ClaimStatus::Paid;
-        claim.updated_at = Clock::get()?.unix_timestamp;
+        claim.updated_at = now;
+        ctx.accounts.member.close_claim(ctx.accounts.dependent.as_deref_mut());
         Ok(())
     }
 
-    /// Denies a pending claim.  Only the protocol authority may call this.
+    /// Denies a pending claim.  Only the claims authority may call this, and
+    /// as with `approve_claim` only for escalated claims or claims no panel
+    /// can be drawn for.
     pub fn deny_claim(ctx: Context<DenyClaim>) -> Result<()> {
         require_keys_eq!(ctx.accounts.authority.key(), ctx.accounts.config.authority, ApolloError::Unauthorized);
         let claim = &mut ctx.accounts.claim;
-        require!(claim.status == ClaimStatus::NeedsReview, ApolloError::InvalidClaimStatus);
+        let claimant = ctx.accounts.member.authority;
+        require!(
+            claim.awaits_authority(&ctx.accounts.registry, &claimant, ctx.program_id)?,
+            ApolloError::InvalidClaimStatus
+        );
+        require!(ctx.accounts.dependent.as_ref().map(|d| d.key()) == claim.dependent, ApolloError::InvalidParameter);
         claim.status = ClaimStatus::Denied;
         claim.updated_at = Clock::get()?.unix_timestamp;
+        ctx.accounts.member.close_claim(ctx.accounts.dependent.as_deref_mut());
         Ok(())
     }
-}
-
-/*
- * Context definitions
- */
 
-/// Context for the `initialize` instruction.
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        seeds = [b"config"],
-        bump,
-        payer = authority,
-        space = Config::LEN,
-    )]
-    pub config: Account<'info, Config>,
-    /// The account that will become the protocol authority.  Pays for the
-    /// initialization of the config account and token pools.
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    /// The system program.
-    pub system_program: Program<'info, System>,
-}
+    /// Appeals a denied claim to a governance vote.  Only the member may
+    /// appeal, at most `MAX_APPEALS` times per claim.  The evidence hash
+    /// refers to new off‑chain documentation supporting the appeal and is
+    /// recorded on both the claim and the proposal.  The proposal is created
+    /// on the member's behalf without the usual stake threshold and uses the
+    /// current voting period and quorum.  Once voting closes the outcome is
+    /// applied with `resolve_appeal`.
+    pub fn appeal_claim(ctx: Context<AppealClaim>, evidence_hash: [u8; 32]) -> Result<()> {
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        let claim = &mut ctx.accounts.claim;
+        require!(ctx.accounts.dependent.as_ref().map(|d| d.key()) == claim.dependent, ApolloError::InvalidParameter);
+        claim.file_appeal(evidence_hash, ctx.accounts.proposal.key(), now)?;
+        let cfg = &mut ctx.accounts.config;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.id = cfg.next_proposal_id;
+        proposal.proposer = ctx.accounts.authority.key();
+        proposal.action = ProposalAction::OverturnDenial { claim: claim.key() };
+        proposal.description_hash = evidence_hash;
+        proposal.created_at = now;
+        proposal.voting_ends_at = now.checked_add(cfg.voting_period).unwrap();
+        proposal.snapshot_slot = clock.slot;
+        proposal.quorum_votes = cfg.quorum_votes;
+        proposal.for_votes = 0;
+        proposal.against_votes = 0;
+        proposal.status = ProposalStatus::Active;
+        proposal.executed_at = 0;
+        proposal.bump = ctx.bumps.proposal;
+        cfg.next_proposal_id = cfg.next_proposal_id.checked_add(1).unwrap();
+        ctx.accounts.member.open_claim(ctx.accounts.dependent.as_deref_mut());
+        Ok(())
+    }
 
-/// Context for creating a policy.  Only the protocol authority may call
-/// this instruction.
-#[derive(Accounts)]
-pub struct CreatePolicy<'info> {
-    #[account(mut, seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
-    #[account(
-        init,
-        payer = authority,
-        space = Policy::LEN,
-        seeds = [b"policy", &config.next_policy_id.to_le_bytes()],
-        bump
-    )]
-    pub policy: Account<'info, Policy>,
-    /// The authority must sign and pay for the account creation.
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Applies the outcome of an appeal once its voting window has closed.
+    /// Anyone may call this.  If the vote passed and its execution window is
+    /// still open, the denial is overturned and the claim is paid from the
+    /// premium pool exactly as in `approve_claim`, and the proposal is marked
+    /// `Executed`.  Otherwise the proposal is marked `Defeated` and the claim
+    /// returns to `Denied`, from where it may be appealed again if appeals
+    /// remain.
+    pub fn resolve_appeal(ctx: Context<ResolveAppeal>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        require!(proposal.status == ProposalStatus::Active, ApolloError::InvalidProposalStatus);
+        require!(now > proposal.voting_ends_at, ApolloError::VotingStillOpen);
+        let claim = &mut ctx.accounts.claim;
+        match proposal.action {
+            ProposalAction::OverturnDenial { claim: target } => {
+                require_keys_eq!(target, claim.key(), ApolloError::InvalidParameter)
+            }
+            _ => return err!(ApolloError::InvalidProposalAction),
+        }
+        require!(claim.status == ClaimStatus::Appealed, ApolloError::InvalidClaimStatus);
+        require!(ctx.accounts.dependent.as_ref().map(|d| d.key()) == claim.dependent, ApolloError::InvalidParameter);
+        if proposal.has_passed(now) && !proposal.is_expired(now) {
+            let dependent = ctx.accounts.dependent.as_deref_mut();
+            let (billed, reason) = claim.apply_fee_schedule(claim.amount, REASON_NONE);
+            let amount =
+                ctx.accounts.member.apply_cost_sharing(&ctx.accounts.policy, now, billed, claim.in_network, dependent);
+            claim.record_approval(billed, amount, reason);
+            require!(ctx.accounts.premium_pool.amount >= amount, ApolloError::InsufficientPoolBalance);
+            let cfg = &ctx.accounts.config;
+            let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.premium_pool.to_account_info(),
+                to: ctx.accounts.payee_usdc_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), amount)?;
+            proposal.status = ProposalStatus::Executed;
+            proposal.executed_at = now;
+        } else {
+            claim.status = ClaimStatus::Denied;
+            proposal.status = ProposalStatus::Defeated;
+        }
+        claim.updated_at = now;
+        ctx.accounts.member.close_claim(ctx.accounts.dependent.as_deref_mut());
+        Ok(())
+    }
 
-/// Context for enrolling a member.  The user pays the first premium in USDC.
-#[derive(Accounts)]
-pub struct EnrollMember<'info> {
-    #[account(mut, seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
-    #[account(has_one = creator)]
-    pub policy: Account<'info, Policy>,
-    #[account(
-        init,
-        payer = authority,
-        space = Member::LEN,
-        seeds = [b"member", authority.key().as_ref()],
-        bump
-    )]
-    pub member: Account<'info, Member>,
-    /// The user joining the policy.  Must provide USDC to pay the first premium.
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    /// User's USDC token account to debit premium from.
-    #[account(mut, constraint = user_usdc_account.mint == config.usdc_mint)]
-    pub user_usdc_account: Account<'info, TokenAccount>,
-    /// Program's premium pool token account that collects USDC premiums.  Must
-    /// be owned by the config PDA.
-    #[account(mut, constraint = premium_pool.mint == config.usdc_mint, constraint = premium_pool.owner == config.key())]
-    pub premium_pool: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
+    /// Executes a passed `SetOffRampOperator` proposal, registering an
+    /// off‑ramp operator or updating its payout account and active flag.
+    /// Anyone may call this.
+    pub fn set_off_ramp_operator(ctx: Context<SetOffRampOperator>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.require_executable(now)?;
+        let operator = &mut ctx.accounts.operator;
+        match proposal.action {
+            ProposalAction::SetOffRampOperator { authority, usdc_account, active } => {
+                require_keys_eq!(ctx.accounts.operator_authority.key(), authority, ApolloError::InvalidParameter);
+                require_keys_eq!(ctx.accounts.usdc_account.key(), usdc_account, ApolloError::InvalidParameter);
+                operator.authority = authority;
+                operator.usdc_account = usdc_account;
+                operator.active = active;
+            }
+            _ => return err!(ApolloError::InvalidProposalAction),
+        }
+        operator.bump = ctx.bumps.operator;
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = now;
+        Ok(())
+    }
 
-/// Context for paying an additional monthly premium.
-#[derive(Accounts)]
-pub struct PayPremium<'info> {
-    #[account(seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
-    pub policy: Account<'info, Policy>,
-    /// Member account is not needed here; premium payments are open.
+    /// Routes a claim's payout through the fiat bridge.  Only the member may
+    /// call this, for a claim that reimburses them.  A program‑owned escrow
+    /// token account is created and becomes the claim's payee.  If the claim
+    /// has not been paid yet, whichever instruction later pays it funds the
+    /// escrow.  If it was already approved and paid to the member, as
+    /// fast‑lane claims are at submission, the member funds the escrow with
+    /// the amount paid from the USDC account it went to, and the windows for
+    /// taking up and refunding the request run from now.  The hash refers to
+    /// the member's off‑chain bank details.  A claim whose payout has been
+    /// settled cannot be routed again.
+    pub fn request_fiat_payout(ctx: Context<RequestFiatPayout>, bank_details_hash: [u8; 32]) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.provider.is_none(), ApolloError::InvalidParameter);
+        // A claim whose payout was already settled through the bridge still
+        // names the (closed) escrow as its payee.
+        require_keys_neq!(claim.payee, ctx.accounts.escrow.key(), ApolloError::InvalidFiatPayoutStatus);
+        if matches!(claim.status, ClaimStatus::Paid | ClaimStatus::PartiallyApproved) {
+            require!(claim.paid_amount > 0, ApolloError::InvalidClaimStatus);
+            let user_usdc_account =
+                ctx.accounts.user_usdc_account.as_ref().ok_or_else(|| error!(ApolloError::InvalidParameter))?;
+            let cpi_accounts = Transfer {
+                from: user_usdc_account.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::transfer(CpiContext::new(cpi_program, cpi_accounts), claim.paid_amount)?;
+            claim.updated_at = Clock::get()?.unix_timestamp;
+        }
+        let request = &mut ctx.accounts.request;
+        request.claim = claim.key();
+        request.escrow = ctx.accounts.escrow.key();
+        request.refund_account = claim.payee;
+        request.bank_details_hash = bank_details_hash;
+        request.operator = Pubkey::default();
+        request.status = FiatPayoutStatus::Pending;
+        request.claimed_at = 0;
+        request.receipt_hash = [0; 32];
+        request.payer = ctx.accounts.authority.key();
+        request.bump = ctx.bumps.request;
+        claim.payee = ctx.accounts.escrow.key();
+        Ok(())
+    }
+
+    /// Withdraws a fiat payout request that was never funded, for example
+    /// because the claim was denied.  Only the member may call this.  The
+    /// claim's payee reverts to the member's USDC account, so a later
+    /// approval, such as one on appeal, pays the member directly, and the
+    /// escrow and request accounts are closed, returning their rent.  The
+    /// member may route the claim again afterwards.
+    pub fn cancel_fiat_payout(ctx: Context<CancelFiatPayout>) -> Result<()> {
+        let request = &ctx.accounts.request;
+        require!(request.status == FiatPayoutStatus::Pending, ApolloError::InvalidFiatPayoutStatus);
+        require!(ctx.accounts.escrow.amount == 0, ApolloError::InvalidFiatPayoutStatus);
+        ctx.accounts.claim.payee = request.refund_account;
+        close_fiat_escrow(
+            &ctx.accounts.config,
+            &ctx.accounts.escrow,
+            ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.token_program,
+        )
+    }
+
+    /// Takes up a funded fiat payout request.  Only an active off‑ramp
+    /// operator may call this, within `FIAT_CLAIM_WINDOW` of the claim being
+    /// paid into escrow.  The operator then pays the member off chain.
+    pub fn claim_fiat_payout(ctx: Context<ClaimFiatPayout>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let claim = &ctx.accounts.claim;
+        require!(
+            matches!(claim.status, ClaimStatus::Paid | ClaimStatus::PartiallyApproved),
+            ApolloError::InvalidClaimStatus
+        );
+        require!(ctx.accounts.escrow.amount > 0, ApolloError::InvalidParameter);
+        let request = &mut ctx.accounts.request;
+        require!(request.status == FiatPayoutStatus::Pending, ApolloError::InvalidFiatPayoutStatus);
+        require!(now <= request.deadline(claim.updated_at)?, ApolloError::FiatPayoutExpired);
+        request.operator = ctx.accounts.operator.key();
+        request.status = FiatPayoutStatus::Claimed;
+        request.claimed_at = now;
+        Ok(())
+    }
+
+    /// Completes a fiat payout once the off‑ramp has settled it.  Anyone may
+    /// call this with a fresh report from the `FiatSettlement` oracle feed on
+    /// the request, observed after the operator took it up and carrying the
+    /// settlement receipt hash.  The escrow is released to the operator's
+    /// USDC account, the escrow and request accounts are closed, returning
+    /// their rent to the member, and the receipt hash is emitted in a
+    /// `FiatPayoutSettled` event.
+    pub fn finalize_fiat_payout(ctx: Context<FinalizeFiatPayout>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let request = &mut ctx.accounts.request;
+        require!(request.status == FiatPayoutStatus::Claimed, ApolloError::InvalidFiatPayoutStatus);
+        let feed = &ctx.accounts.settlement_feed;
+        let report = &ctx.accounts.settlement_report;
+        let settled = read_oracle_report(feed, report, FeedKind::FiatSettlement, &request.key(), now)?;
+        require!(settled != 0, ApolloError::InvalidParameter);
+        require!(report.observed_at >= request.claimed_at, ApolloError::StaleOracleReport);
+        request.receipt_hash = report.data_hash;
+        request.status = FiatPayoutStatus::Settled;
+        let cfg = &ctx.accounts.config;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow.to_account_info(),
+            to: ctx.accounts.operator_usdc_account.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let amount = ctx.accounts.escrow.amount;
+        token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), amount)?;
+        emit!(FiatPayoutSettled {
+            request: request.key(),
+            claim: request.claim,
+            operator: request.operator,
+            amount,
+            receipt_hash: request.receipt_hash,
+        });
+        close_fiat_escrow(
+            &ctx.accounts.config,
+            &ctx.accounts.escrow,
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.token_program,
+        )
+    }
+
+    /// Refunds a funded fiat payout request to the member's USDC account and
+    /// closes the escrow and request accounts, returning their rent to the
+    /// member.  Anyone may call this once a pending request has gone
+    /// unclaimed for `FIAT_CLAIM_WINDOW` after the escrow was funded, or a
+    /// claimed request has not been settled within `FIAT_SETTLEMENT_WINDOW`.
+    /// The claim's payee reverts to the member's USDC account, so the member
+    /// may route it again.
+    pub fn expire_fiat_payout(ctx: Context<ExpireFiatPayout>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let claim = &mut ctx.accounts.claim;
+        require!(
+            matches!(claim.status, ClaimStatus::Paid | ClaimStatus::PartiallyApproved),
+            ApolloError::InvalidClaimStatus
+        );
+        let request = &mut ctx.accounts.request;
+        require!(now > request.deadline(claim.updated_at)?, ApolloError::FiatPayoutNotExpired);
+        request.status = FiatPayoutStatus::Refunded;
+        claim.payee = request.refund_account;
+        let cfg = &ctx.accounts.config;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow.to_account_info(),
+            to: ctx.accounts.refund_account.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let amount = ctx.accounts.escrow.amount;
+        token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), amount)?;
+        close_fiat_escrow(
+            &ctx.accounts.config,
+            &ctx.accounts.escrow,
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.token_program,
+        )
+    }
+
+    /// Registers the caller as a claims reviewer, or refreshes their weight
+    /// if already registered.  The reviewer's selection weight is their
+    /// current staked APH, which must be at least `Config.min_reviewer_stake`.
+    /// Weights are only refreshed when the reviewer re-registers.  The stake
+    /// is locked until the reviewer deregisters, so the same APH cannot back
+    /// several registry entries.  A Reviewer account holding the caller's
+    /// reputation is created on first registration and kept across
+    /// deregistration.
+    pub fn register_reviewer(ctx: Context<RegisterReviewer>) -> Result<()> {
+        let weight = ctx.accounts.stake.amount;
+        require!(weight >= ctx.accounts.config.min_reviewer_stake, ApolloError::InsufficientStake);
+        ctx.accounts.stake.reviewer = true;
+        let reviewer = ctx.accounts.authority.key();
+        let record = &mut ctx.accounts.reviewer;
+        if record.authority == Pubkey::default() {
+            record.authority = reviewer;
+            record.reputation = INITIAL_REPUTATION;
+            record.bump = ctx.bumps.reviewer;
+        }
+        let registry = &mut ctx.accounts.registry;
+        registry.bump = ctx.bumps.registry;
+        if let Some(entry) = registry.reviewers.iter_mut().find(|e| e.authority == reviewer) {
+            entry.weight = weight;
+        } else {
+            require!(registry.reviewers.len() < MAX_REVIEWERS, ApolloError::ReviewerRegistryFull);
+            registry.reviewers.push(ReviewerEntry { authority: reviewer, weight });
+        }
+        Ok(())
+    }
+
+    /// Removes the caller from the reviewer registry and unlocks their stake.
+    /// Panels that have already been selected are unaffected.
+    pub fn deregister_reviewer(ctx: Context<DeregisterReviewer>) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let reviewer = ctx.accounts.authority.key();
+        let idx = registry
+            .reviewers
+            .iter()
+            .position(|e| e.authority == reviewer)
+            .ok_or_else(|| error!(ApolloError::ReviewerNotRegistered))?;
+        registry.reviewers.swap_remove(idx);
+        ctx.accounts.stake.reviewer = false;
+        Ok(())
+    }
+
+    /// Selects a panel of five distinct reviewers for a claim awaiting
+    /// review and moves the claim to `UnderReview`.  Anyone may call this.
+    /// Reviewers are drawn from the registry without replacement, weighted
+    /// by stake (see `draw_panel`).  The entropy is the hash of the first
+    /// slot at or after `Claim.review_seed_slot`, which was fixed when the
+    /// claim entered review, so the caller cannot pick a favourable slot by
+    /// choosing when to call.  If that hash has dropped out of the SlotHashes
+    /// sysvar, `refresh_review_seed` commits to a new slot.  The claimant is
+    /// never eligible to review their own claim.
+    pub fn select_review_panel(ctx: Context<SelectReviewPanel>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::NeedsReview, ApolloError::InvalidClaimStatus);
+        let candidates = ctx.accounts.registry.eligible(&ctx.accounts.member.authority);
+        require!(candidates.len() >= PANEL_SIZE, ApolloError::InsufficientReviewers);
+
+        let clock = Clock::get()?;
+        require!(clock.slot > claim.review_seed_slot, ApolloError::ReviewSeedPending);
+        require!(
+            clock.slot - claim.review_seed_slot < SLOT_HASHES_RETAINED,
+            ApolloError::ReviewSeedExpired
+        );
+        let seed = slot_hash_from(&ctx.accounts.slot_hashes, claim.review_seed_slot)?
+            .ok_or_else(|| error!(ApolloError::ReviewSeedPending))?;
+        let claim_key = claim.key();
+        let reviewers = draw_panel(candidates, &seed, &claim_key);
+
+        let now = clock.unix_timestamp;
+        let panel = &mut ctx.accounts.panel;
+        panel.claim = claim_key;
+        panel.reviewers = reviewers;
+        panel.selected_at = now;
+        panel.deadline = now.checked_add(ctx.accounts.config.review_period).unwrap();
+        panel.bump = ctx.bumps.panel;
+        claim.status = ClaimStatus::UnderReview;
+        claim.updated_at = now;
+        Ok(())
+    }
+
+    /// Commits a claim awaiting review to a new seed slot, `REVIEW_SEED_DELAY`
+    /// slots from now.  Anyone may call this, but only once the committed
+    /// slot's hash is too old to be read from the SlotHashes sysvar, so that
+    /// `select_review_panel` can run again.
+    pub fn refresh_review_seed(ctx: Context<RefreshReviewSeed>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::NeedsReview, ApolloError::InvalidClaimStatus);
+        let slot = Clock::get()?.slot;
+        require!(
+            slot.saturating_sub(claim.review_seed_slot) >= SLOT_HASHES_RETAINED,
+            ApolloError::InvalidParameter
+        );
+        claim.review_seed_slot = slot.checked_add(REVIEW_SEED_DELAY).unwrap();
+        Ok(())
+    }
+
+    /// Escrows a panel reviewer's APH bond for a claim under review.  The
+    /// bond is `Config.review_bond_bps` of the claim amount, treating one
+    /// USDC base unit as one APH base unit, scaled by the reviewer's
+    /// reputation.  Bonds are held in a per‑claim vault owned by the config
+    /// PDA and must be posted before the reviewer's vote is accepted.
+    pub fn post_review_bond(ctx: Context<PostReviewBond>) -> Result<()> {
+        let claim = &ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::UnderReview, ApolloError::InvalidClaimStatus);
+        let panel = &mut ctx.accounts.panel;
+        let seat = panel.seat_of(&ctx.accounts.authority.key())?;
+        require!(panel.bonds[seat] == 0, ApolloError::BondAlreadyPosted);
+
+        let reviewer = &mut ctx.accounts.reviewer;
+        let bond = reviewer.bond_for(claim.amount, ctx.accounts.config.review_bond_bps);
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.user_aph_account.to_account_info(),
+            to: ctx.accounts.bond_vault.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), bond)?;
+
+        panel.bonds[seat] = bond;
+        if panel.vault_payer == Pubkey::default() {
+            panel.vault_payer = ctx.accounts.authority.key();
+        }
+        reviewer.active_bonds = reviewer.active_bonds.checked_add(1).unwrap();
+        reviewer.bonded_amount = reviewer.bonded_amount.checked_add(bond).unwrap();
+        Ok(())
+    }
+
+    /// Records a panel reviewer's vote on a claim under review.  The reviewer
+    /// must have posted their bond, may vote only once and only before the
+    /// panel deadline.  A partial vote approves a lower amount than was
+    /// claimed.
+    pub fn cast_claim_vote(ctx: Context<CastClaimVote>, vote: ReviewVote) -> Result<()> {
+        let claim = &ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::UnderReview, ApolloError::InvalidClaimStatus);
+        if let ReviewVote::Partial { amount } = vote {
+            require!(amount > 0 && amount < claim.amount, ApolloError::InvalidParameter);
+        }
+        let panel = &mut ctx.accounts.panel;
+        require!(Clock::get()?.unix_timestamp <= panel.deadline, ApolloError::ReviewClosed);
+        let seat = panel.seat_of(&ctx.accounts.authority.key())?;
+        require!(panel.bonds[seat] > 0, ApolloError::BondNotPosted);
+        require!(panel.votes[seat].is_none(), ApolloError::AlreadyVoted);
+        panel.votes[seat] = Some(vote);
+        Ok(())
+    }
+
+    /// Resolves a claim under panel review.  Anyone may call this once a
+    /// majority of the panel agrees, or after the deadline using the votes
+    /// cast so far, provided at least `PANEL_MAJORITY` seats voted.  If the
+    /// claim is approved the payout is the lower median of the approving
+    /// votes (a full approval counts as the claimed amount) after cost
+    /// sharing, and is paid from the premium pool to the claimant exactly
+    /// like `approve_claim`; otherwise the claim is denied.  If the deadline
+    /// passes with fewer votes or a tie, the claim is `Escalated` to the
+    /// claims authority.
+    pub fn finalize_claim_review(ctx: Context<FinalizeClaimReview>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::UnderReview, ApolloError::InvalidClaimStatus);
+        let panel = &mut ctx.accounts.panel;
+        let (outcome, median) = panel.decide(claim.amount, now)?;
+        require!(ctx.accounts.dependent.as_ref().map(|d| d.key()) == claim.dependent, ApolloError::InvalidParameter);
+
+        match outcome {
+            ReviewOutcome::Approved => {
+                let (median, reason) = claim.apply_fee_schedule(median, REASON_PANEL_ADJUSTED);
+                let dependent = ctx.accounts.dependent.as_deref_mut();
+                let amount = ctx.accounts.member.apply_cost_sharing(
+                    &ctx.accounts.policy,
+                    now,
+                    median,
+                    claim.in_network,
+                    dependent,
+                );
+                claim.record_approval(median, amount, reason);
+                let pool_balance = ctx.accounts.premium_pool.amount;
+                require!(pool_balance >= amount, ApolloError::InsufficientPoolBalance);
+                let cfg = &ctx.accounts.config;
+                let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
+                let cpi_accounts = Transfer {
+                    from: ctx.accounts.premium_pool.to_account_info(),
+                    to: ctx.accounts.payee_usdc_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                };
+                let cpi_program = ctx.accounts.token_program.to_account_info();
+                token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), amount)?;
+                panel.approved_amount = amount;
+                ctx.accounts.member.close_claim(ctx.accounts.dependent.as_deref_mut());
+            }
+            ReviewOutcome::Denied => {
+                claim.status = ClaimStatus::Denied;
+                ctx.accounts.member.close_claim(ctx.accounts.dependent.as_deref_mut());
+            }
+            ReviewOutcome::Escalated => claim.status = ClaimStatus::Escalated,
+            ReviewOutcome::Pending => unreachable!(),
+        }
+        panel.outcome = outcome;
+        claim.updated_at = now;
+        Ok(())
+    }
+
+    /// Creates the program‑owned APH reward pool and insurance reserve used
+    /// to settle review bonds.  Anyone may call this once; the reward pool is
+    /// funded by ordinary token transfers from the community fund.
+    pub fn init_review_pools(_ctx: Context<InitReviewPools>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Settles one panel seat after the panel has been finalized.  Anyone
+    /// may call this.  A reviewer who voted with the outcome gets their bond
+    /// back, an APH reward of `Config.review_reward_bps` of the bond from the
+    /// reward pool and `Config.review_fee_bps` of the claim amount in USDC
+    /// from accrued protocol fees, both capped by what is available.  A
+    /// reviewer who voted against the outcome or did not vote has
+    /// `Config.review_slash_bps` of their bond moved to the insurance reserve
+    /// and gets the remainder back.  If the panel escalated without a
+    /// decision, voters are refunded without reward.  The reviewer's
+    /// reputation and history are updated in every case.  Settling the last
+    /// bonded seat closes the bond vault and returns its rent to the reviewer
+    /// who created it.
+    pub fn settle_review_bond(ctx: Context<SettleReviewBond>) -> Result<()> {
+        let panel = &mut ctx.accounts.panel;
+        require!(panel.outcome != ReviewOutcome::Pending, ApolloError::ReviewStillOpen);
+        let reviewer = &mut ctx.accounts.reviewer;
+        let seat = panel.seat_of(&reviewer.authority)?;
+        let last = panel.settle_seat(seat)?;
+
+        let bond = panel.bonds[seat];
+        let verdict = panel.verdict(seat);
+        reviewer.record_verdict(verdict);
+        if bond == 0 {
+            // Nothing was escrowed for this seat.
+            if last {
+                close_bond_vault(ctx.accounts)?;
+            }
+            return Ok(());
+        }
+        reviewer.active_bonds = reviewer.active_bonds.checked_sub(1).unwrap();
+        reviewer.bonded_amount = reviewer.bonded_amount.checked_sub(bond).unwrap();
+
+        let cfg = &ctx.accounts.config;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[cfg.bump]]];
+        let BondSettlement { slashed, reward, fee_share } = cfg.settle_bond(
+            verdict,
+            bond,
+            ctx.accounts.claim.amount,
+            ctx.accounts.reward_pool.amount,
+            ctx.accounts.premium_pool.amount,
+        );
+        let bond_vault = ctx.accounts.bond_vault.as_ref().ok_or_else(|| error!(ApolloError::InvalidParameter))?;
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        if slashed > 0 {
+            let cpi_accounts = Transfer {
+                from: bond_vault.to_account_info(),
+                to: ctx.accounts.insurance_reserve.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            };
+            token::transfer(CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds), slashed)?;
+        }
+        let cpi_accounts = Transfer {
+            from: bond_vault.to_account_info(),
+            to: ctx.accounts.reviewer_aph_account.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        };
+        token::transfer(CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds), bond - slashed)?;
+
+        if reward > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.reward_pool.to_account_info(),
+                to: ctx.accounts.reviewer_aph_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            };
+            token::transfer(CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds), reward)?;
+        }
+        if fee_share > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.premium_pool.to_account_info(),
+                to: ctx.accounts.reviewer_usdc_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            };
+            token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), fee_share)?;
+            let cfg = &mut ctx.accounts.config;
+            cfg.protocol_fees -= fee_share;
+        }
+        if last {
+            close_bond_vault(ctx.accounts)?;
+        }
+        Ok(())
+    }
+}
+
+/*
+ * Helpers
+ */
+
+/// Amounts moved when a panel seat's bond is settled.
+pub struct BondSettlement {
+    /// APH moved from the bond to the insurance reserve.
+    pub slashed: u64,
+    /// APH paid from the reward pool.
+    pub reward: u64,
+    /// USDC paid from accrued protocol fees.
+    pub fee_share: u64,
+}
+
+/// Premium refunds owed on cancelling a membership.
+pub struct CancellationRefund {
+    /// Refund to the payer of the most recently paid period.
+    pub last: u64,
+    /// Refund to the payer of the period before it.
+    pub prior: u64,
+    /// Administrative fees included in the refunds.
+    pub fees: u64,
+}
+
+/// A group member's premium split between the sponsor and the employee.
+pub struct GroupShares {
+    /// Amount paid by the sponsor.
+    pub sponsor_share: u64,
+    /// Administrative fee included in the sponsor's share.
+    pub sponsor_fee: u64,
+    /// Amount paid by the employee.
+    pub employee_share: u64,
+}
+
+/// Premium owed for one period after applying any staking discount.
+pub struct PremiumQuote {
+    /// Index into `Config.discount_tiers` of the tier applied, if any.
+    pub tier: Option<u8>,
+    /// Amount deducted from the policy's administrative fee.
+    pub discount: u64,
+    /// Administrative fee collected after the discount.
+    pub fee: u64,
+    /// Total amount to transfer: risk premium plus discounted fee.
+    pub amount: u64,
+}
+
+/// Computes the premium for `policy` covering a subscriber and `dependents`
+/// dependents.  The payer's TWAB over the configured window selects a
+/// discount tier, whose rebate applies to the administrative fee only; the
+/// risk premium and dependent premiums are never discounted.
+pub fn quote_premium(cfg: &Config, policy: &Policy, dependents: u8, stake: Option<&Stake>, now: i64) -> PremiumQuote {
+    let twab = stake
+        .and_then(|s| s.twab(now.saturating_sub(cfg.tier_twab_window), now))
+        .unwrap_or(0);
+    let tier = cfg.discount_tier(twab);
+    let discount = tier.map_or(0, |i| {
+        let rebate_bps = cfg.discount_tiers[i].rebate_bps as u128;
+        (policy.admin_fee as u128 * rebate_bps / BPS_DENOMINATOR as u128) as u64
+    });
+    PremiumQuote {
+        tier: tier.map(|i| i as u8),
+        discount,
+        fee: policy.admin_fee - discount,
+        amount: policy.full_premium(dependents).checked_sub(discount).unwrap(),
+    }
+}
+
+/// Prices a claim billed at `amount` against the fee schedule for its
+/// procedure category, if one exists.  Returns the allowed amount that caps
+/// the claim's payouts and whether the claim is flagged for review.  Once a
+/// price oracle is appointed, a claim no schedule covers cannot be checked
+/// against market prices, so it is flagged and never takes the fast lane.
+pub fn price_claim(cfg: &Config, schedule: Option<&FeeSchedule>, amount: u64) -> (Option<u64>, bool) {
+    match schedule {
+        Some(schedule) => (Some(schedule.allowed_amount), amount > schedule.billed_at(cfg.price_flag_percentile)),
+        None => (None, cfg.price_oracle != Pubkey::default()),
+    }
+}
+
+/// Returns the version of a member's policy whose terms apply to a premium
+/// period starting at `start`: `current` until it is superseded, and
+/// afterwards `next`, which must be a later version of the same policy in
+/// effect at `start`.  A member several versions behind therefore moves
+/// straight to the version in effect rather than through outdated ones.
+pub fn policy_for_period<'a, P: std::ops::Deref<Target = Policy>>(
+    current: &'a P,
+    next: Option<&'a P>,
+    start: i64,
+) -> Result<&'a P> {
+    if start < current.superseded_at {
+        return Ok(current);
+    }
+    let next = next.ok_or_else(|| error!(ApolloError::PolicySuperseded))?;
+    require!(
+        next.first_version == current.first_version && next.version > current.version,
+        ApolloError::InvalidParameter
+    );
+    require!(next.effective_at <= start && start < next.superseded_at, ApolloError::PolicySuperseded);
+    Ok(next)
+}
+
+/// Mints a member's proof‑of‑coverage NFT and locks it: the config PDA is
+/// approved as delegate for the token, so that `revoke_coverage_nft` can burn
+/// it later, and the token account is frozen so it cannot be transferred.
+/// The owner must sign because approving a delegate requires the owner.
+pub fn issue_coverage_nft<'info>(
+    config: &Account<'info, Config>,
+    coverage_mint: &Account<'info, Mint>,
+    coverage_token: &Account<'info, TokenAccount>,
+    owner: &Signer<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[config.bump]]];
+    let cpi_program = token_program.to_account_info();
+    let cpi_accounts = MintTo {
+        mint: coverage_mint.to_account_info(),
+        to: coverage_token.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    token::mint_to(CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds), 1)?;
+    let cpi_accounts = Approve {
+        to: coverage_token.to_account_info(),
+        delegate: config.to_account_info(),
+        authority: owner.to_account_info(),
+    };
+    token::approve(CpiContext::new(cpi_program.clone(), cpi_accounts), 1)?;
+    let cpi_accounts = FreezeAccount {
+        account: coverage_token.to_account_info(),
+        mint: coverage_mint.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    token::freeze_account(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds))
+}
+
+/// Thaws and burns a member's proof‑of‑coverage NFT using the config PDA's
+/// freeze authority and delegate approval.  The emptied token account is
+/// left thawed so a new NFT can be issued into it on reinstatement.
+pub fn revoke_coverage_nft<'info>(
+    config: &Account<'info, Config>,
+    coverage_mint: &Account<'info, Mint>,
+    coverage_token: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[config.bump]]];
+    let cpi_program = token_program.to_account_info();
+    let cpi_accounts = ThawAccount {
+        account: coverage_token.to_account_info(),
+        mint: coverage_mint.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    token::thaw_account(CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds))?;
+    let cpi_accounts = Burn {
+        mint: coverage_mint.to_account_info(),
+        from: coverage_token.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    token::burn(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), 1)
+}
+
+/// Closes a claim's bond vault once every bonded seat has been settled,
+/// returning its rent to the reviewer who created it.  Anything left in the
+/// vault, such as tokens sent to it directly, is swept into the insurance
+/// reserve first so that it cannot block the close.  Does nothing if no bond
+/// was ever posted.
+pub fn close_bond_vault(accounts: &mut SettleReviewBond) -> Result<()> {
+    let Some(vault) = accounts.bond_vault.as_mut() else {
+        return Ok(());
+    };
+    let rent_receiver = accounts.vault_payer.as_ref().ok_or_else(|| error!(ApolloError::InvalidParameter))?;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[accounts.config.bump]]];
+    vault.reload()?;
+    if vault.amount > 0 {
+        let cpi_accounts = Transfer {
+            from: vault.to_account_info(),
+            to: accounts.insurance_reserve.to_account_info(),
+            authority: accounts.config.to_account_info(),
+        };
+        let cpi_program = accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds), vault.amount)?;
+    }
+    let cpi_accounts = CloseAccount {
+        account: vault.to_account_info(),
+        destination: rent_receiver.to_account_info(),
+        authority: accounts.config.to_account_info(),
+    };
+    let cpi_program = accounts.token_program.to_account_info();
+    token::close_account(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds))
+}
+
+/// Closes a fiat payout escrow, which must be empty, returning its rent to
+/// the member who requested the payout.
+pub fn close_fiat_escrow<'info>(
+    config: &Account<'info, Config>,
+    escrow: &Account<'info, TokenAccount>,
+    rent_receiver: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[config.bump]]];
+    let cpi_accounts = CloseAccount {
+        account: escrow.to_account_info(),
+        destination: rent_receiver,
+        authority: config.to_account_info(),
+    };
+    token::close_account(CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds))
+}
+
+/// Reads the hash of the first produced slot at or after `slot` from the
+/// SlotHashes sysvar, or `None` if there is none yet.  The sysvar is too
+/// large to deserialize on chain, so its entries are read in place: an 8
+/// byte vector length followed by `(slot: u64, hash: [u8; 32])` entries,
+/// newest first.
+pub fn slot_hash_from(slot_hashes: &AccountInfo, slot: u64) -> Result<Option<[u8; 32]>> {
+    let data = slot_hashes.try_borrow_data()?;
+    require!(data.len() >= 8, ApolloError::InvalidParameter);
+    let len = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+    let entries = data.get(8..8 + len * 40).ok_or_else(|| error!(ApolloError::InvalidParameter))?;
+    let mut found = None;
+    for entry in entries.chunks_exact(40) {
+        if u64::from_le_bytes(entry[..8].try_into().unwrap()) < slot {
+            break;
+        }
+        found = Some(entry[8..].try_into().unwrap());
+    }
+    Ok(found)
+}
+
+/// Draws `PANEL_SIZE` distinct reviewers from `candidates` without
+/// replacement, each draw weighted by the remaining candidates' weights.
+/// Each draw hashes `seed`, the claim address and the seat index.  Weights
+/// are summed in `u128` so that no registry can overflow the total.
+pub fn draw_panel(mut candidates: Vec<ReviewerEntry>, seed: &[u8; 32], claim: &Pubkey) -> [Pubkey; PANEL_SIZE] {
+    let mut reviewers = [Pubkey::default(); PANEL_SIZE];
+    for (i, seat) in reviewers.iter_mut().enumerate() {
+        let total = candidates.iter().try_fold(0u128, |sum, e| sum.checked_add(e.weight as u128)).unwrap();
+        let digest = hashv(&[seed, claim.as_ref(), &(i as u64).to_le_bytes()]);
+        let mut pick = u128::from_le_bytes(digest.to_bytes()[..16].try_into().unwrap()) % total;
+        let mut idx = 0;
+        while pick >= candidates[idx].weight as u128 {
+            pick -= candidates[idx].weight as u128;
+            idx += 1;
+        }
+        *seat = candidates.swap_remove(idx).authority;
+    }
+    reviewers
+}
+
+/// Counts the distinct members of `feed`'s signer set that signed `message`
+/// in Ed25519 program instructions of the current transaction, read from the
+/// instructions sysvar.  Only signatures whose public key, signature and
+/// message are all embedded in the Ed25519 instruction itself are counted, so
+/// the key and message checked here are exactly those the Ed25519 program
+/// verified.
+pub fn count_oracle_signatures(feed: &OracleFeed, instructions: &AccountInfo, message: &[u8]) -> Result<u8> {
+    let signers = &feed.signers[..feed.signer_count as usize];
+    let mut signed = [false; MAX_ORACLE_SIGNERS];
+    let mut index = 0;
+    while let Ok(ix) = sysvar::instructions::load_instruction_at_checked(index, instructions) {
+        index += 1;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        let data = &ix.data;
+        require!(!data.is_empty(), ApolloError::InvalidOracleSignature);
+        // Header: signature count and a padding byte, followed by one
+        // 14 byte offsets entry per signature.
+        for n in 0..data[0] as usize {
+            let entry = 2 + n * 14;
+            require!(data.len() >= entry + 14, ApolloError::InvalidOracleSignature);
+            let field = |offset: usize| u16::from_le_bytes([data[entry + offset], data[entry + offset + 1]]);
+            let key_offset = field(4) as usize;
+            let message_offset = field(8) as usize;
+            let message_len = field(10) as usize;
+            if field(2) != u16::MAX || field(6) != u16::MAX || field(12) != u16::MAX {
+                continue;
+            }
+            require!(data.len() >= key_offset + 32, ApolloError::InvalidOracleSignature);
+            require!(data.len() >= message_offset + message_len, ApolloError::InvalidOracleSignature);
+            if &data[message_offset..message_offset + message_len] != message {
+                continue;
+            }
+            let key = Pubkey::try_from(&data[key_offset..key_offset + 32]).unwrap();
+            if let Some(seat) = signers.iter().position(|s| *s == key) {
+                signed[seat] = true;
+            }
+        }
+    }
+    Ok(signed.iter().filter(|s| **s).count() as u8)
+}
+
+/// Returns the value of `report` after checking that it was posted to `feed`,
+/// that `feed` is the feed of `kind`, that it concerns `subject` and that it
+/// is no older than the feed's `max_staleness`.  Instructions consuming
+/// oracle data read it through this helper.
+pub fn read_oracle_report(
+    feed: &Account<OracleFeed>,
+    report: &OracleReport,
+    kind: FeedKind,
+    subject: &Pubkey,
+    now: i64,
+) -> Result<u64> {
+    require!(feed.kind == kind, ApolloError::InvalidParameter);
+    require_keys_eq!(report.feed, feed.key(), ApolloError::InvalidParameter);
+    require_keys_eq!(report.subject, *subject, ApolloError::InvalidParameter);
+    require!(now - report.observed_at <= feed.max_staleness, ApolloError::StaleOracleReport);
+    Ok(report.value)
+}
+
+/*
+ * Context definitions
+ */
+
+/// Context for the `initialize` instruction.
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        seeds = [b"config"],
+        bump,
+        payer = authority,
+        space = Config::LEN,
+    )]
+    pub config: Account<'info, Config>,
+    /// The account that will become the protocol authority.  Pays for the
+    /// initialization of the config account and token pools.
     #[account(mut)]
     pub authority: Signer<'info>,
-    #[account(mut, constraint = user_usdc_account.mint == config.usdc_mint)]
-    pub user_usdc_account: Account<'info, TokenAccount>,
-    #[account(mut, constraint = premium_pool.mint == config.usdc_mint, constraint = premium_pool.owner == config.key())]
-    pub premium_pool: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    /// The system program.
+    pub system_program: Program<'info, System>,
 }
 
-/// Context for staking APH into the capital pool.
+/// Context for creating the premium and capital pools.
 #[derive(Accounts)]
-pub struct StakeAPH<'info> {
+pub struct InitPools<'info> {
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
     #[account(
-        init_if_needed,
-        payer = authority,
-        space = Stake::LEN,
-        seeds = [b"stake", authority.key().as_ref()],
-        bump
+        init,
+        payer = payer,
+        seeds = [b"premium_pool"],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = config,
+    )]
+    pub premium_pool: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"capital_pool"],
+        bump,
+        token::mint = aph_mint,
+        token::authority = config,
     )]
-    pub stake: Account<'info, Stake>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    #[account(mut, constraint = user_aph_account.mint == config.aph_mint)]
-    pub user_aph_account: Account<'info, TokenAccount>,
-    #[account(mut, constraint = capital_pool.mint == config.aph_mint, constraint = capital_pool.owner == config.key())]
     pub capital_pool: Account<'info, TokenAccount>,
+    #[account(address = config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+    #[account(address = config.aph_mint)]
+    pub aph_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
-/// Context for unstaking APH.
+/// Context for creating a policy from a passed `CreatePolicy` proposal.
 #[derive(Accounts)]
-pub struct UnstakeAPH<'info> {
+pub struct CreatePolicy<'info> {
     #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
-    #[account(mut, seeds = [b"stake", authority.key().as_ref()], bump = stake.bump)]
-    pub stake: Account<'info, Stake>,
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        init,
+        payer = payer,
+        space = Policy::LEN,
+        seeds = [b"policy".as_ref(), &config.next_policy_id.to_le_bytes()],
+        bump
+    )]
+    pub policy: Account<'info, Policy>,
+    /// Any account may execute a passed proposal and pay for the policy.
     #[account(mut)]
-    pub authority: Signer<'info>,
-    #[account(mut, constraint = user_aph_account.mint == config.aph_mint)]
-    pub user_aph_account: Account<'info, TokenAccount>,
-    #[account(mut, constraint = capital_pool.mint == config.aph_mint, constraint = capital_pool.owner == config.key())]
-    pub capital_pool: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// Context for submitting a claim.
+/// Context for publishing a new policy version from a passed `UpdatePolicy`
+/// proposal.
 #[derive(Accounts)]
-pub struct SubmitClaim<'info> {
-    #[account(seeds = [b"config"], bump = config.bump)]
+pub struct UpdatePolicy<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
-    #[account(mut, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
-    pub member: Account<'info, Member>,
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    /// The version being replaced.
     #[account(mut)]
-    pub authority: Signer<'info>,
-    /// The policy on which the claim is being made.  Included for future
-    /// validations.
-    pub policy: Account<'info, Policy>,
+    pub current_policy: Account<'info, Policy>,
     #[account(
         init,
-        payer = authority,
-        space = Claim::LEN,
-        seeds = [b"claim", member.key().as_ref(), &member.claim_count.to_le_bytes()],
+        payer = payer,
+        space = Policy::LEN,
+        seeds = [b"policy".as_ref(), &config.next_policy_id.to_le_bytes()],
         bump
     )]
-    pub claim: Account<'info, Claim>,
-    #[account(mut, constraint = premium_pool.mint == config.usdc_mint, constraint = premium_pool.owner == config.key())]
-    pub premium_pool: Account<'info, TokenAccount>,
-    #[account(mut, constraint = user_usdc_account.mint == config.usdc_mint)]
-    pub user_usdc_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub policy: Account<'info, Policy>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// Context for approving a claim.
+/// Context for deactivating a policy from a passed `DeactivatePolicy`
+/// proposal.
 #[derive(Accounts)]
-pub struct ApproveClaim<'info> {
+pub struct DeactivatePolicy<'info> {
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub policy: Account<'info, Policy>,
+}
+
+/// Context for creating a governance proposal.
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
     #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
-    #[account(mut, seeds = [b"member", member.authority.as_ref()], bump = member.bump)]
-    pub member: Account<'info, Member>,
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [b"proposal".as_ref(), &config.next_proposal_id.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// The proposer's stake, used to enforce the proposal threshold.
+    #[account(seeds = [b"stake", proposer.key().as_ref()], bump = stake.bump)]
+    pub stake: Account<'info, Stake>,
     #[account(mut)]
-    pub claim: Account<'info, Claim>,
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for voting on a proposal.
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    /// The voter's stake determines their voting power.
+    #[account(seeds = [b"stake", voter.key().as_ref()], bump = stake.bump)]
+    pub stake: Account<'info, Stake>,
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
     #[account(mut)]
+    pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for executing (or defeating) a proposal.  Permissionless.
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+/// Context for enrolling a member.  The user pays the first premium in USDC.
+#[derive(Accounts)]
+pub struct EnrollMember<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(constraint = policy.is_open(Clock::get()?.unix_timestamp) @ ApolloError::PolicyNotOpen)]
+    pub policy: Account<'info, Policy>,
+    #[account(
+        init,
+        payer = authority,
+        space = Member::LEN,
+        seeds = [b"member", authority.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    /// The user joining the policy.  Must provide USDC to pay the first premium.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// The user's stake, if any, used to determine their discount tier.
+    #[account(seeds = [b"stake", authority.key().as_ref()], bump = stake.bump)]
+    pub stake: Option<Account<'info, Stake>>,
+    /// User's USDC token account to debit premium from.
+    #[account(mut, constraint = user_usdc_account.mint == config.usdc_mint)]
+    pub user_usdc_account: Account<'info, TokenAccount>,
+    /// Program's premium pool token account that collects USDC premiums.  Must
+    /// be owned by the config PDA.
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    /// Mint of the member's proof‑of‑coverage NFT.  Already exists if the
+    /// wallet closed an earlier membership.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"coverage_mint", member.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = config,
+        mint::freeze_authority = config,
+    )]
+    pub coverage_mint: Account<'info, Mint>,
+    /// The member's associated token account that receives the NFT.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = coverage_mint,
+        associated_token::authority = authority,
+    )]
+    pub coverage_token: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for paying an additional monthly premium on behalf of a member.
+#[derive(Accounts)]
+pub struct PayPremium<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    /// The member whose coverage is extended.
+    #[account(mut, seeds = [b"member", member.authority.as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    /// The later version of the member's policy in effect at the start of
+    /// the period, required once the member's version is superseded.
+    #[account(constraint = next_policy.first_version == policy.first_version @ ApolloError::InvalidParameter)]
+    pub next_policy: Option<Account<'info, Policy>>,
+    /// The member or a third party paying on their behalf.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The payer's stake, if any, used to determine their discount tier.
+    #[account(seeds = [b"stake", payer.key().as_ref()], bump = stake.bump)]
+    pub stake: Option<Account<'info, Stake>>,
+    /// Payer's USDC token account to debit the premium from.
+    #[account(mut, constraint = payer_usdc_account.mint == config.usdc_mint)]
+    pub payer_usdc_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    /// Needed to reissue the proof‑of‑coverage NFT after a recorded lapse.
+    #[account(mut, address = member.coverage_mint)]
+    pub coverage_mint: Account<'info, Mint>,
+    #[account(mut, associated_token::mint = coverage_mint, associated_token::authority = member.authority)]
+    pub coverage_token: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for switching a member to another policy.
+#[derive(Accounts)]
+pub struct ChangePolicy<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
     pub authority: Signer<'info>,
-    #[account(mut, constraint = premium_pool.mint == config.usdc_mint, constraint = premium_pool.owner == config.key())]
+    /// The member's current policy.
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    #[account(constraint = new_policy.is_open(Clock::get()?.unix_timestamp) @ ApolloError::PolicyNotOpen)]
+    pub new_policy: Account<'info, Policy>,
+    /// Outside the open enrollment window, either the life event attestor
+    /// or the `LifeEvent` oracle feed and its report on the member.
+    pub attestor: Option<Signer<'info>>,
+    pub life_event_feed: Option<Account<'info, OracleFeed>>,
+    pub life_event_report: Option<Account<'info, OracleReport>>,
+    /// The member's stake, if any, used to determine their discount tier.
+    #[account(seeds = [b"stake", authority.key().as_ref()], bump = stake.bump)]
+    pub stake: Option<Account<'info, Stake>>,
+    /// Member's USDC account, debited with the prorated premium difference.
+    #[account(
+        mut,
+        constraint = user_usdc_account.mint == config.usdc_mint,
+        constraint = user_usdc_account.owner == authority.key()
+    )]
+    pub user_usdc_account: Account<'info, TokenAccount>,
+    /// USDC account of whoever paid the current period, credited with the
+    /// prorated premium difference when the new policy is cheaper.
+    #[account(
+        mut,
+        constraint = refund_usdc_account.mint == config.usdc_mint,
+        constraint = refund_usdc_account.owner == member.last_premium_payer @ ApolloError::InvalidPayee
+    )]
+    pub refund_usdc_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
     pub premium_pool: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for adding a dependent to a membership.
+#[derive(Accounts)]
+pub struct AddDependent<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    #[account(
+        init,
+        payer = authority,
+        space = Dependent::LEN,
+        seeds = [b"dependent", member.key().as_ref(), &member.next_dependent_id.to_le_bytes()],
+        bump
+    )]
+    pub dependent: Account<'info, Dependent>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
     #[account(mut, constraint = user_usdc_account.mint == config.usdc_mint)]
     pub user_usdc_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for removing a dependent from a membership.
+#[derive(Accounts)]
+pub struct RemoveDependent<'info> {
+    #[account(mut, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"dependent", member.key().as_ref(), &dependent.id.to_le_bytes()],
+        bump = dependent.bump
+    )]
+    pub dependent: Account<'info, Dependent>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// Member's USDC account, which receives the refund.
+    #[account(
+        mut,
+        constraint = refund_usdc_account.mint == config.usdc_mint,
+        constraint = refund_usdc_account.owner == authority.key() @ ApolloError::InvalidPayee
+    )]
+    pub refund_usdc_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for creating an employer group.
+#[derive(Accounts)]
+pub struct CreateEmployerGroup<'info> {
+    #[account(
+        init,
+        payer = sponsor,
+        space = EmployerGroup::LEN,
+        seeds = [b"employer_group", sponsor.key().as_ref()],
+        bump
+    )]
+    pub group: Account<'info, EmployerGroup>,
+    /// The negotiated policy; must be open for enrollment.
+    #[account(constraint = policy.is_open(Clock::get()?.unix_timestamp) @ ApolloError::PolicyNotOpen)]
+    pub policy: Account<'info, Policy>,
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for adding a member to an employer group.
+#[derive(Accounts)]
+pub struct JoinEmployerGroup<'info> {
+    #[account(mut, seeds = [b"employer_group", sponsor.key().as_ref()], bump = group.bump)]
+    pub group: Account<'info, EmployerGroup>,
+    pub sponsor: Signer<'info>,
+    #[account(mut, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    pub authority: Signer<'info>,
+}
+
+/// Context for removing a member from an employer group.
+#[derive(Accounts)]
+pub struct LeaveEmployerGroup<'info> {
+    #[account(mut, seeds = [b"employer_group", group.sponsor.as_ref()], bump = group.bump)]
+    pub group: Account<'info, EmployerGroup>,
+    #[account(mut, constraint = member.employer_group == group.key() @ ApolloError::InvalidParameter)]
+    pub member: Account<'info, Member>,
+    /// The sponsor or the member.
+    pub signer: Signer<'info>,
+}
+
+/// Context for paying an employer group's premiums.  Member and employee
+/// USDC accounts are passed as remaining accounts.
+#[derive(Accounts)]
+pub struct PayGroupPremium<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"employer_group", sponsor.key().as_ref()], bump = group.bump)]
+    pub group: Account<'info, EmployerGroup>,
+    #[account(address = group.policy)]
+    pub policy: Account<'info, Policy>,
+    /// The next version of the group's policy, required once the group's
+    /// version is superseded.
+    #[account(constraint = next_policy.first_version == policy.first_version @ ApolloError::InvalidParameter)]
+    pub next_policy: Option<Account<'info, Policy>>,
+    pub sponsor: Signer<'info>,
+    #[account(mut, constraint = sponsor_usdc_account.mint == config.usdc_mint)]
+    pub sponsor_usdc_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for cancelling a membership.
+#[derive(Accounts)]
+pub struct CancelMembership<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    pub authority: Signer<'info>,
+    #[account(mut, address = member.coverage_mint)]
+    pub coverage_mint: Account<'info, Mint>,
+    #[account(mut, associated_token::mint = coverage_mint, associated_token::authority = authority)]
+    pub coverage_token: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for refunding and closing a cancelled membership.
+#[derive(Accounts)]
+pub struct CloseMembership<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, close = authority, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// USDC account of the payer of the member's last premium period, which
+    /// receives the refund.
+    #[account(
+        mut,
+        constraint = refund_usdc_account.mint == config.usdc_mint,
+        constraint = refund_usdc_account.owner == member.last_premium_payer @ ApolloError::InvalidPayee
+    )]
+    pub refund_usdc_account: Account<'info, TokenAccount>,
+    /// USDC account of the payer of the period before it, required when
+    /// part of that period is refunded.
+    #[account(
+        mut,
+        constraint = prior_refund_usdc_account.mint == config.usdc_mint,
+        constraint = prior_refund_usdc_account.owner == member.prior_premium_payer @ ApolloError::InvalidPayee
+    )]
+    pub prior_refund_usdc_account: Option<Account<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for recording a coverage lapse.  Permissionless.
+#[derive(Accounts)]
+pub struct MarkLapsed<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"member", member.authority.as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    #[account(mut, address = member.coverage_mint)]
+    pub coverage_mint: Account<'info, Mint>,
+    #[account(mut, associated_token::mint = coverage_mint, associated_token::authority = member.authority)]
+    pub coverage_token: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for staking APH into the capital pool.
+#[derive(Accounts)]
+pub struct StakeAPH<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Stake::LEN,
+        seeds = [b"stake", authority.key().as_ref()],
+        bump
+    )]
+    pub stake: Account<'info, Stake>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut, constraint = user_aph_account.mint == config.aph_mint)]
+    pub user_aph_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"capital_pool"], bump)]
+    pub capital_pool: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for unstaking APH.
+#[derive(Accounts)]
+pub struct UnstakeAPH<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"stake", authority.key().as_ref()], bump = stake.bump)]
+    pub stake: Account<'info, Stake>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut, constraint = user_aph_account.mint == config.aph_mint)]
+    pub user_aph_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"capital_pool"], bump)]
+    pub capital_pool: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
 }
 
-/// Context for denying a claim.
-#[derive(Accounts)]
-pub struct DenyClaim<'info> {
-    #[account(mut, seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
-    #[account(mut)]
-    pub claim: Account<'info, Claim>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
+/// Context for reading a stake's time‑weighted average balance.
+#[derive(Accounts)]
+pub struct GetTwab<'info> {
+    pub stake: Account<'info, Stake>,
+}
+
+/// Context for executing a `ConfigureOracleFeed` proposal.  Permissionless.
+#[derive(Accounts)]
+#[instruction(kind: FeedKind)]
+pub struct ConfigureOracleFeed<'info> {
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OracleFeed::LEN,
+        seeds = [b"oracle_feed".as_ref(), &[kind as u8]],
+        bump
+    )]
+    pub feed: Account<'info, OracleFeed>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for posting an oracle report.
+#[derive(Accounts)]
+#[instruction(subject: Pubkey)]
+pub struct PostOracleReport<'info> {
+    #[account(seeds = [b"oracle_feed".as_ref(), &[feed.kind as u8]], bump = feed.bump)]
+    pub feed: Account<'info, OracleFeed>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OracleReport::LEN,
+        seeds = [b"oracle_report", feed.key().as_ref(), subject.as_ref()],
+        bump
+    )]
+    pub report: Account<'info, OracleReport>,
+    /// CHECK: address is constrained to the Instructions sysvar.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for registering a provider.
+#[derive(Accounts)]
+pub struct RegisterProvider<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = authority,
+        space = Provider::LEN,
+        seeds = [b"provider", authority.key().as_ref()],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+    /// The provider's USDC account for direct payouts.
+    #[account(constraint = usdc_account.mint == config.usdc_mint)]
+    pub usdc_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for attesting a provider's credentials.
+#[derive(Accounts)]
+pub struct AttestProvider<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"provider", provider.authority.as_ref()], bump = provider.bump)]
+    pub provider: Account<'info, Provider>,
+    #[account(address = config.provider_attestor @ ApolloError::Unauthorized)]
+    pub attestor: Signer<'info>,
+}
+
+/// Context for executing a `VerifyProvider` proposal.  Permissionless.
+#[derive(Accounts)]
+pub struct VerifyProvider<'info> {
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut, seeds = [b"provider", provider.authority.as_ref()], bump = provider.bump)]
+    pub provider: Account<'info, Provider>,
+}
+
+/// Context for setting a fee schedule.
+#[derive(Accounts)]
+#[instruction(procedure_category: u16)]
+pub struct SetFeeSchedule<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = FeeSchedule::LEN,
+        seeds = [b"fee_schedule".as_ref(), &procedure_category.to_le_bytes()],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+    #[account(mut, address = config.price_oracle @ ApolloError::Unauthorized)]
+    pub oracle: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for submitting a claim.
+#[derive(Accounts)]
+pub struct SubmitClaim<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// The member's policy, which determines the grace period and limits.
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    /// The dependent the claim is filed for, if it is not for the subscriber.
+    #[account(mut, seeds = [b"dependent", member.key().as_ref(), &dependent.id.to_le_bytes()], bump = dependent.bump)]
+    pub dependent: Option<Account<'info, Dependent>>,
+    /// The provider to pay directly, if any.
+    #[account(seeds = [b"provider", provider.authority.as_ref()], bump = provider.bump)]
+    pub provider: Option<Account<'info, Provider>>,
+    /// The `ProviderCredential` oracle feed and its report on `provider`, if
+    /// the claimant relies on them to establish network status.
+    pub credential_feed: Option<Account<'info, OracleFeed>>,
+    pub credential_report: Option<Account<'info, OracleReport>>,
+    /// CHECK: address of the FeeSchedule for the metadata's procedure
+    /// category, required when metadata is given.  The address is checked,
+    /// and the schedule read if it exists, in `submit_claim`.
+    pub fee_schedule: Option<UncheckedAccount<'info>>,
+    /// The claimant's proof‑of‑coverage NFT.
+    #[account(
+        constraint = member.proves_coverage(&coverage_token, &authority.key()) @ ApolloError::CoverageNotProven
+    )]
+    pub coverage_token: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = Claim::LEN,
+        seeds = [
+            b"claim",
+            member.key().as_ref(),
+            &member.enrollment_id.to_le_bytes(),
+            &member.claim_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, Claim>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    /// The provider's USDC account if a provider is given, otherwise the
+    /// claimant's.
+    #[account(mut, constraint = payee_usdc_account.mint == config.usdc_mint)]
+    pub payee_usdc_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for approving a claim.
+#[derive(Accounts)]
+pub struct ApproveClaim<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"member", member.authority.as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    /// The dependent named on the claim, if any.
+    #[account(mut, constraint = dependent.member == member.key() @ ApolloError::InvalidParameter)]
+    pub dependent: Option<Account<'info, Dependent>>,
+    #[account(mut, constraint = claim.belongs_to(&member.key(), member.enrollment_id) @ ApolloError::InvalidParameter)]
+    pub claim: Account<'info, Claim>,
+    /// CHECK: the reviewer registry PDA, which does not exist until the
+    /// first reviewer registers.  Read by `Claim::awaits_authority`.
+    #[account(seeds = [b"reviewer_registry"], bump)]
+    pub registry: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    #[account(mut, address = claim.payee @ ApolloError::InvalidPayee)]
+    pub payee_usdc_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for denying a claim.
+#[derive(Accounts)]
+pub struct DenyClaim<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"member", member.authority.as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(mut, constraint = claim.belongs_to(&member.key(), member.enrollment_id) @ ApolloError::InvalidParameter)]
+    pub claim: Account<'info, Claim>,
+    /// The dependent named on the claim, if any.
+    #[account(mut, constraint = dependent.member == member.key() @ ApolloError::InvalidParameter)]
+    pub dependent: Option<Account<'info, Dependent>>,
+    /// CHECK: the reviewer registry PDA, which does not exist until the
+    /// first reviewer registers.  Read by `Claim::awaits_authority`.
+    #[account(seeds = [b"reviewer_registry"], bump)]
+    pub registry: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Context for appealing a denied claim.
+#[derive(Accounts)]
+pub struct AppealClaim<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(mut, constraint = claim.belongs_to(&member.key(), member.enrollment_id) @ ApolloError::InvalidParameter)]
+    pub claim: Account<'info, Claim>,
+    /// The dependent named on the claim, if any.
+    #[account(mut, constraint = dependent.member == member.key() @ ApolloError::InvalidParameter)]
+    pub dependent: Option<Account<'info, Dependent>>,
+    #[account(
+        init,
+        payer = authority,
+        space = Proposal::LEN,
+        seeds = [b"proposal".as_ref(), &config.next_proposal_id.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for resolving an appeal.  Permissionless.
+#[derive(Accounts)]
+pub struct ResolveAppeal<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut, seeds = [b"member", member.authority.as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    /// The dependent named on the claim, if any.
+    #[account(mut, constraint = dependent.member == member.key() @ ApolloError::InvalidParameter)]
+    pub dependent: Option<Account<'info, Dependent>>,
+    #[account(mut, constraint = claim.belongs_to(&member.key(), member.enrollment_id) @ ApolloError::InvalidParameter)]
+    pub claim: Account<'info, Claim>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    #[account(mut, address = claim.payee @ ApolloError::InvalidPayee)]
+    pub payee_usdc_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for executing a `SetOffRampOperator` proposal.  Permissionless.
+#[derive(Accounts)]
+pub struct SetOffRampOperator<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"proposal".as_ref(), &proposal.id.to_le_bytes()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OffRampOperator::LEN,
+        seeds = [b"off_ramp", operator_authority.key().as_ref()],
+        bump
+    )]
+    pub operator: Account<'info, OffRampOperator>,
+    /// CHECK: checked against the proposal in the handler.
+    pub operator_authority: UncheckedAccount<'info>,
+    /// The operator's USDC account, checked against the proposal.
+    #[account(constraint = usdc_account.mint == config.usdc_mint)]
+    pub usdc_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for routing a claim through the fiat bridge.
+#[derive(Accounts)]
+pub struct RequestFiatPayout<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(mut, constraint = claim.belongs_to(&member.key(), member.enrollment_id) @ ApolloError::InvalidParameter)]
+    pub claim: Account<'info, Claim>,
+    #[account(
+        init,
+        payer = authority,
+        space = FiatPayoutRequest::LEN,
+        seeds = [b"fiat_payout", claim.key().as_ref()],
+        bump
+    )]
+    pub request: Account<'info, FiatPayoutRequest>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"fiat_escrow", claim.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = config,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(address = config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+    /// The USDC account a paid claim was paid to, which funds the escrow.
+    /// Only needed for claims that have already been paid.
+    #[account(mut, address = claim.payee @ ApolloError::InvalidPayee)]
+    pub user_usdc_account: Option<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for withdrawing an unfunded fiat payout request.
+#[derive(Accounts)]
+pub struct CancelFiatPayout<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"member", authority.key().as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(mut, constraint = claim.belongs_to(&member.key(), member.enrollment_id) @ ApolloError::InvalidParameter)]
+    pub claim: Account<'info, Claim>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"fiat_payout", claim.key().as_ref()],
+        bump = request.bump
+    )]
+    pub request: Account<'info, FiatPayoutRequest>,
+    #[account(mut, address = request.escrow)]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for an off‑ramp operator taking up a fiat payout.
+#[derive(Accounts)]
+pub struct ClaimFiatPayout<'info> {
+    #[account(address = request.claim)]
+    pub claim: Account<'info, Claim>,
+    #[account(mut, seeds = [b"fiat_payout", claim.key().as_ref()], bump = request.bump)]
+    pub request: Account<'info, FiatPayoutRequest>,
+    #[account(address = request.escrow)]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"off_ramp", authority.key().as_ref()],
+        bump = operator.bump,
+        constraint = operator.active @ ApolloError::Unauthorized
+    )]
+    pub operator: Account<'info, OffRampOperator>,
+    pub authority: Signer<'info>,
+}
+
+/// Context for settling a fiat payout.  Permissionless.
+#[derive(Accounts)]
+pub struct FinalizeFiatPayout<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, close = payer, seeds = [b"fiat_payout", request.claim.as_ref()], bump = request.bump)]
+    pub request: Account<'info, FiatPayoutRequest>,
+    #[account(mut, address = request.escrow)]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(address = request.operator)]
+    pub operator: Account<'info, OffRampOperator>,
+    #[account(mut, address = operator.usdc_account @ ApolloError::InvalidPayee)]
+    pub operator_usdc_account: Account<'info, TokenAccount>,
+    pub settlement_feed: Account<'info, OracleFeed>,
+    pub settlement_report: Account<'info, OracleReport>,
+    /// CHECK: receives the escrow's rent; constrained to `request.payer`.
+    #[account(mut, address = request.payer)]
+    pub payer: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for refunding an expired fiat payout.  Permissionless.
+#[derive(Accounts)]
+pub struct ExpireFiatPayout<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, address = request.claim)]
+    pub claim: Account<'info, Claim>,
+    #[account(mut, close = payer, seeds = [b"fiat_payout", claim.key().as_ref()], bump = request.bump)]
+    pub request: Account<'info, FiatPayoutRequest>,
+    #[account(mut, address = request.escrow)]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(mut, address = request.refund_account @ ApolloError::InvalidPayee)]
+    pub refund_account: Account<'info, TokenAccount>,
+    /// CHECK: receives the escrow's rent; constrained to `request.payer`.
+    #[account(mut, address = request.payer)]
+    pub payer: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for registering as a claims reviewer.
+#[derive(Accounts)]
+pub struct RegisterReviewer<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ReviewerRegistry::LEN,
+        seeds = [b"reviewer_registry"],
+        bump
+    )]
+    pub registry: Account<'info, ReviewerRegistry>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Reviewer::LEN,
+        seeds = [b"reviewer", authority.key().as_ref()],
+        bump
+    )]
+    pub reviewer: Account<'info, Reviewer>,
+    /// The reviewer's stake determines eligibility and selection weight.
+    #[account(mut, seeds = [b"stake", authority.key().as_ref()], bump = stake.bump)]
+    pub stake: Account<'info, Stake>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for leaving the reviewer registry.
+#[derive(Accounts)]
+pub struct DeregisterReviewer<'info> {
+    #[account(mut, seeds = [b"reviewer_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ReviewerRegistry>,
+    #[account(mut, seeds = [b"stake", authority.key().as_ref()], bump = stake.bump)]
+    pub stake: Account<'info, Stake>,
+    pub authority: Signer<'info>,
+}
+
+/// Context for selecting a review panel.  Permissionless; the caller pays
+/// for the panel account.
+#[derive(Accounts)]
+pub struct SelectReviewPanel<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"reviewer_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ReviewerRegistry>,
+    #[account(mut, constraint = claim.belongs_to(&member.key(), member.enrollment_id) @ ApolloError::InvalidParameter)]
+    pub claim: Account<'info, Claim>,
+    /// The claimant's member account, used to exclude them from the panel.
+    pub member: Account<'info, Member>,
+    #[account(
+        init,
+        payer = payer,
+        space = ReviewPanel::LEN,
+        seeds = [b"review_panel", claim.key().as_ref()],
+        bump
+    )]
+    pub panel: Account<'info, ReviewPanel>,
+    /// CHECK: address is constrained to the SlotHashes sysvar.
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for committing a claim awaiting review to a new seed slot.
+#[derive(Accounts)]
+pub struct RefreshReviewSeed<'info> {
+    #[account(mut)]
+    pub claim: Account<'info, Claim>,
+}
+
+/// Context for posting a review bond.
+#[derive(Accounts)]
+pub struct PostReviewBond<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub claim: Account<'info, Claim>,
+    #[account(mut, seeds = [b"review_panel", claim.key().as_ref()], bump = panel.bump)]
+    pub panel: Account<'info, ReviewPanel>,
+    #[account(mut, seeds = [b"reviewer", authority.key().as_ref()], bump = reviewer.bump)]
+    pub reviewer: Account<'info, Reviewer>,
+    /// Per‑claim APH vault holding the panel's bonds.  Created by the first
+    /// reviewer to post.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"bond_vault", claim.key().as_ref()],
+        bump,
+        token::mint = aph_mint,
+        token::authority = config,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    #[account(address = config.aph_mint)]
+    pub aph_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut, constraint = user_aph_account.mint == config.aph_mint)]
+    pub user_aph_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for a panel reviewer voting on a claim.
+#[derive(Accounts)]
+pub struct CastClaimVote<'info> {
+    pub claim: Account<'info, Claim>,
+    #[account(mut, seeds = [b"review_panel", claim.key().as_ref()], bump = panel.bump)]
+    pub panel: Account<'info, ReviewPanel>,
+    pub authority: Signer<'info>,
+}
+
+/// Context for resolving a panel review.  Permissionless.
+#[derive(Accounts)]
+pub struct FinalizeClaimReview<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, constraint = claim.belongs_to(&member.key(), member.enrollment_id) @ ApolloError::InvalidParameter)]
+    pub claim: Account<'info, Claim>,
+    #[account(mut, seeds = [b"member", member.authority.as_ref()], bump = member.bump)]
+    pub member: Account<'info, Member>,
+    #[account(address = member.policy)]
+    pub policy: Account<'info, Policy>,
+    /// The dependent named on the claim, if any.
+    #[account(mut, constraint = dependent.member == member.key() @ ApolloError::InvalidParameter)]
+    pub dependent: Option<Account<'info, Dependent>>,
+    #[account(mut, seeds = [b"review_panel", claim.key().as_ref()], bump = panel.bump)]
+    pub panel: Account<'info, ReviewPanel>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    /// The payee recorded on the claim.  Checked since the caller is not
+    /// trusted.
+    #[account(mut, address = claim.payee @ ApolloError::InvalidPayee)]
+    pub payee_usdc_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for creating the reward pool and insurance reserve.
+#[derive(Accounts)]
+pub struct InitReviewPools<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"reward_pool"],
+        bump,
+        token::mint = aph_mint,
+        token::authority = config,
+    )]
+    pub reward_pool: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"insurance_reserve"],
+        bump,
+        token::mint = aph_mint,
+        token::authority = config,
+    )]
+    pub insurance_reserve: Account<'info, TokenAccount>,
+    #[account(address = config.aph_mint)]
+    pub aph_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for settling a panel seat's bond.  Permissionless.
+#[derive(Accounts)]
+pub struct SettleReviewBond<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub claim: Account<'info, Claim>,
+    #[account(mut, seeds = [b"review_panel", claim.key().as_ref()], bump = panel.bump)]
+    pub panel: Account<'info, ReviewPanel>,
+    #[account(mut, seeds = [b"reviewer", reviewer.authority.as_ref()], bump = reviewer.bump)]
+    pub reviewer: Account<'info, Reviewer>,
+    /// The claim's bond vault.  May be omitted if no seat ever posted a bond.
+    #[account(mut, seeds = [b"bond_vault", claim.key().as_ref()], bump)]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+    /// CHECK: the reviewer who created the bond vault, constrained to
+    /// `panel.vault_payer`.  Receives the vault's rent when the last bonded
+    /// seat is settled, and may be omitted otherwise.
+    #[account(mut, address = panel.vault_payer)]
+    pub vault_payer: Option<UncheckedAccount<'info>>,
+    #[account(mut, seeds = [b"reward_pool"], bump)]
+    pub reward_pool: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"insurance_reserve"], bump)]
+    pub insurance_reserve: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"premium_pool"], bump)]
+    pub premium_pool: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = reviewer_aph_account.mint == config.aph_mint,
+        constraint = reviewer_aph_account.owner == reviewer.authority @ ApolloError::Unauthorized
+    )]
+    pub reviewer_aph_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = reviewer_usdc_account.mint == config.usdc_mint,
+        constraint = reviewer_usdc_account.owner == reviewer.authority @ ApolloError::Unauthorized
+    )]
+    pub reviewer_usdc_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/*
+ * Account types
+ */
+
+#[account]
+pub struct Config {
+    /// Claims authority for reviews that are not auto‑approved.  Can only be
+    /// changed by a `SetAuthority` proposal.
+    pub authority: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub aph_mint: Pubkey,
+    pub fast_claim_threshold: u64,
+    pub next_policy_id: u64,
+    /// Length of the voting window for new proposals, in seconds.
+    pub voting_period: i64,
+    /// Minimum total votes cast for a proposal to be valid.
+    pub quorum_votes: u64,
+    /// Minimum stake required to create a proposal.
+    pub proposal_threshold: u64,
+    pub next_proposal_id: u64,
+    pub bump: u8,
+    /// Staking discount tiers, ordered by increasing `min_stake`.
+    pub discount_tiers: [DiscountTier; DISCOUNT_TIERS],
+    /// Window (in seconds) over which a payer's TWAB is measured.
+    pub tier_twab_window: i64,
+    /// Minimum stake required to register as a claims reviewer.
+    pub min_reviewer_stake: u64,
+    /// Review bond as a fraction of the claim amount, in basis points.
+    pub review_bond_bps: u16,
+    /// Time a review panel has to reach a decision, in seconds.
+    pub review_period: i64,
+    /// Share of a dissenting or absent reviewer's bond that is slashed.
+    pub review_slash_bps: u16,
+    /// APH reward for a consensus reviewer, relative to their bond.
+    pub review_reward_bps: u16,
+    /// USDC fee share for a consensus reviewer, relative to the claim amount.
+    pub review_fee_bps: u16,
+    /// Administrative fees collected into the premium pool and not yet paid
+    /// out to reviewers.
+    pub protocol_fees: u64,
+    /// Window during which members may change policies with
+    /// `change_policy`.  Empty when both are zero.
+    pub open_enrollment_start: i64,
+    pub open_enrollment_end: i64,
+    /// Signer attesting qualifying life events that allow a policy change
+    /// outside the open enrollment window, or the default pubkey if none.
+    pub life_event_attestor: Pubkey,
+    /// Id assigned to the next enrollment.  Claim addresses include it so
+    /// that a wallet re‑enrolling after closing its membership cannot
+    /// collide with its old claims, and claims record it so that old claims
+    /// cannot be appealed, approved or paid against the new enrollment.
+    pub next_enrollment_id: u64,
+    /// Signer attesting provider credentials, or the default pubkey if none.
+    pub provider_attestor: Pubkey,
+    /// Signer maintaining fee schedules, or the default pubkey if none.
+    pub price_oracle: Pubkey,
+    /// Claims billed above this percentile of their fee schedule's market
+    /// amounts are flagged for review.  One of `FEE_SCHEDULE_PERCENTILES`.
+    pub price_flag_percentile: u8,
+}
+
+impl Config {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + DiscountTier::LEN * DISCOUNT_TIERS + 8 + 8 + 2 + 8
+        + 2 + 2 + 2 + 8 + 8 + 8 + 32 + 8 + 32 + 32 + 1;
+
+    /// Returns the index of the highest tier whose minimum stake is covered
+    /// by `twab`, or `None` if the balance is below the lowest tier.
+    pub fn discount_tier(&self, twab: u64) -> Option<usize> {
+        self.discount_tiers.iter().rposition(|t| t.min_stake > 0 && twab >= t.min_stake)
+    }
+
+    /// Applies a prorated change of `fee_delta` in administrative fees to
+    /// `protocol_fees` and returns the change actually applied.  A negative
+    /// delta is a fee refund and is capped at the fees still held.
+    pub fn apply_fee_delta(&mut self, fee_delta: i128) -> i128 {
+        let applied = fee_delta.max(-(self.protocol_fees as i128));
+        self.protocol_fees = self.protocol_fees.checked_add_signed(applied as i64).unwrap();
+        applied
+    }
+
+    /// Splits a settled seat's `bond` for a claim of `claim_amount`.  Seats
+    /// that dissented or missed the vote have `review_slash_bps` of the bond
+    /// slashed.  Consensus seats earn `review_reward_bps` of the bond, capped
+    /// by `reward_pool`, and `review_fee_bps` of the claim amount, capped by
+    /// both `protocol_fees` and `premium_pool`.
+    pub fn settle_bond(
+        &self,
+        verdict: SeatVerdict,
+        bond: u64,
+        claim_amount: u64,
+        reward_pool: u64,
+        premium_pool: u64,
+    ) -> BondSettlement {
+        let bps = |amount: u64, bps: u16| (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        match verdict {
+            SeatVerdict::Consensus => BondSettlement {
+                slashed: 0,
+                reward: bps(bond, self.review_reward_bps).min(reward_pool),
+                fee_share: bps(claim_amount, self.review_fee_bps).min(self.protocol_fees).min(premium_pool),
+            },
+            SeatVerdict::Dissent | SeatVerdict::Missed => {
+                BondSettlement { slashed: bps(bond, self.review_slash_bps), reward: 0, fee_share: 0 }
+            }
+            SeatVerdict::Unjudged => BondSettlement { slashed: 0, reward: 0, fee_share: 0 },
+        }
+    }
+}
+
+/// A staking tier granting a rebate on the administrative fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiscountTier {
+    /// Minimum TWAB (in APH base units) required for the tier.
+    pub min_stake: u64,
+    /// Rebate applied to the administrative fee, in basis points.
+    pub rebate_bps: u16,
+}
+
+impl DiscountTier {
+    pub const LEN: usize = 8 + 2;
+
+    /// The Bronze to Enterprise schedule from the tokenomics paper, assuming
+    /// APH uses 6 decimals.
+    pub fn defaults() -> [DiscountTier; DISCOUNT_TIERS] {
+        const APH: u64 = 1_000_000;
+        [
+            DiscountTier { min_stake: 1_000 * APH, rebate_bps: 500 },
+            DiscountTier { min_stake: 5_000 * APH, rebate_bps: 1_000 },
+            DiscountTier { min_stake: 25_000 * APH, rebate_bps: 2_000 },
+            DiscountTier { min_stake: 100_000 * APH, rebate_bps: 3_000 },
+            DiscountTier { min_stake: 250_000 * APH, rebate_bps: 4_000 },
+        ]
+    }
+}
+
+#[account]
+pub struct Policy {
+    pub creator: Pubkey,
+    /// Portion of the monthly premium that funds claims.  Never discounted.
+    pub risk_premium: u64,
+    /// Platform administrative fee charged monthly.  Eligible for staking
+    /// discounts.
+    pub admin_fee: u64,
+    pub coverage_limit: u64,
+    pub bump: u8,
+    /// Time after `Member.paid_through` during which coverage continues
+    /// while a payment is overdue, in seconds.
+    pub grace_period: i64,
+    /// Maximum benefits paid to a member per benefit year, in USDC.
+    pub annual_max: u64,
+    pub cost_sharing: CostSharing,
+    /// Version number, starting at 1.  Each `update_policy` creates a new
+    /// Policy account with the next version.
+    pub version: u32,
+    /// Time from which this version accepts enrollments.
+    pub effective_at: i64,
+    /// Time from which `next_version` replaces this version, or `i64::MAX`.
+    pub superseded_at: i64,
+    /// The next version of this policy, or the default pubkey if none.
+    pub next_version: Pubkey,
+    /// False once deactivated by governance.
+    pub active: bool,
+    /// Monthly premium added for each dependent.  Funds claims and is never
+    /// discounted.
+    pub dependent_premium: u64,
+    /// Cost sharing for claims that are not paid to a verified provider.
+    /// `cost_sharing` applies to in‑network claims.
+    pub out_of_network: CostSharing,
+    /// Version 1 of this policy.  Shared by every version, so that a later
+    /// version can be recognised without walking `next_version`.
+    pub first_version: Pubkey,
+}
+
+impl Policy {
+    pub const LEN: usize =
+        8 + 32 + 8 + 8 + 8 + 1 + 8 + 8 + CostSharing::LEN + 4 + 8 + 8 + 32 + 1 + 8 + CostSharing::LEN + 32;
+
+    /// Copies governance‑approved terms into the policy.
+    pub fn set_terms(&mut self, terms: &PolicyTerms) {
+        self.risk_premium = terms.risk_premium;
+        self.admin_fee = terms.admin_fee;
+        self.coverage_limit = terms.coverage_limit;
+        self.annual_max = terms.annual_max;
+        self.grace_period = terms.grace_period;
+        self.cost_sharing = terms.cost_sharing;
+        self.dependent_premium = terms.dependent_premium;
+        self.out_of_network = terms.out_of_network;
+    }
+
+    /// Returns true if new members may enroll in this version at `now`.
+    pub fn is_open(&self, now: i64) -> bool {
+        self.active && self.effective_at <= now && now < self.superseded_at
+    }
+
+    /// Undiscounted monthly premium for a subscriber with `dependents`
+    /// dependents.
+    pub fn full_premium(&self, dependents: u8) -> u64 {
+        let dependent_premiums = self.dependent_premium.checked_mul(dependents as u64).unwrap();
+        self.risk_premium.checked_add(self.admin_fee).unwrap().checked_add(dependent_premiums).unwrap()
+    }
+}
+
+/// The terms of a policy version, as proposed to governance.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PolicyTerms {
+    pub risk_premium: u64,
+    pub admin_fee: u64,
+    pub coverage_limit: u64,
+    pub annual_max: u64,
+    pub grace_period: i64,
+    pub cost_sharing: CostSharing,
+    pub dependent_premium: u64,
+    pub out_of_network: CostSharing,
+}
+
+impl PolicyTerms {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + CostSharing::LEN + 8 + CostSharing::LEN;
+
+    /// Checks that the terms are well formed.  Out‑of‑network cost sharing
+    /// must be set explicitly: all‑zero terms would pay every unverified
+    /// claim, including reimbursements to members, in full.
+    pub fn validate(&self) -> Result<()> {
+        require!(self.risk_premium > 0, ApolloError::InvalidParameter);
+        require!(self.coverage_limit > 0, ApolloError::InvalidParameter);
+        require!(self.annual_max >= self.coverage_limit, ApolloError::InvalidParameter);
+        require!(self.grace_period >= 0, ApolloError::InvalidParameter);
+        require!(self.out_of_network != CostSharing::default(), ApolloError::InvalidParameter);
+        self.cost_sharing.validate()?;
+        self.out_of_network.validate()
+    }
+}
+
+/// The member's share of covered claim costs under a policy.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CostSharing {
+    /// Amount each covered person pays per benefit year before the plan
+    /// pays.
+    pub deductible: u64,
+    /// Fixed amount the member pays per claim once the deductible is met.
+    pub copay: u64,
+    /// Member's share of the remainder after the copay, in basis points.
+    pub coinsurance_bps: u16,
+    /// Cap on the deductible, copays and coinsurance paid by a subscriber
+    /// and their dependents per benefit year, after which the plan pays
+    /// covered amounts in full.
+    pub out_of_pocket_max: u64,
+    /// Cap on the combined deductibles of a subscriber and their dependents
+    /// per benefit year.
+    pub family_deductible: u64,
+}
+
+impl CostSharing {
+    pub const LEN: usize = 8 + 8 + 2 + 8 + 8;
+
+    /// Checks that the cost sharing parameters are well formed.
+    pub fn validate(&self) -> Result<()> {
+        require!(self.coinsurance_bps as u64 <= BPS_DENOMINATOR, ApolloError::InvalidParameter);
+        require!(self.out_of_pocket_max >= self.deductible, ApolloError::InvalidParameter);
+        require!(self.family_deductible >= self.deductible, ApolloError::InvalidParameter);
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Member {
+    pub authority: Pubkey,
+    pub policy: Pubkey,
+    pub active: bool,
+    pub join_timestamp: i64,
+    pub claim_count: u64,
+    pub bump: u8,
+    /// Mint of the member's proof‑of‑coverage NFT.
+    pub coverage_mint: Pubkey,
+    /// Premiums have been paid for coverage up to this timestamp.
+    pub paid_through: i64,
+    /// Total USDC paid in premiums for this member, by anyone.
+    pub total_premiums_paid: u64,
+    /// Start of the current benefit year.  Benefit years run for
+    /// `BENEFIT_YEAR` from the enrollment date.
+    pub benefit_year_start: i64,
+    /// USDC paid out on the member's claims in the current benefit year.
+    pub year_paid: u64,
+    /// Claims submitted in the current benefit year.
+    pub year_claim_count: u64,
+    /// Deductible met by the subscriber in the current benefit year.
+    pub year_deductible: u64,
+    /// Deductible, copays and coinsurance paid by the subscriber and all
+    /// dependents in the current benefit year.
+    pub year_out_of_pocket: u64,
+    /// Version of `policy` whose terms apply to the member.
+    pub policy_version: u32,
+    /// Number of dependents currently covered.
+    pub dependent_count: u8,
+    /// Id used to derive the next Dependent account.
+    pub next_dependent_id: u64,
+    /// Deductible met by the subscriber and all dependents in the current
+    /// benefit year.
+    pub family_year_deductible: u64,
+    /// The employer group paying the member's premiums, or the default
+    /// pubkey if none.
+    pub employer_group: Pubkey,
+    /// Unique id of this enrollment, used in claim addresses.
+    pub enrollment_id: u64,
+    /// Claims submitted and not yet paid or denied.
+    pub open_claims: u64,
+    /// Set by `cancel_membership`.  A cancelled member cannot pay premiums
+    /// or submit claims and is closed by `close_membership`.
+    pub cancelled: bool,
+    /// Premium refund owed for the unused part of the paid period, paid by
+    /// `close_membership`.
+    pub refund_due: u64,
+    /// Premium charged for the most recently paid period, its administrative
+    /// fee share and who paid it.  Refunds on cancellation are prorated from
+    /// these and paid back to that payer.
+    pub last_premium: u64,
+    pub last_premium_fee: u64,
+    pub last_premium_payer: Pubkey,
+    /// Observation time of the last `LifeEvent` oracle report used to change
+    /// policy.  Only newer reports allow another change.
+    pub last_life_event: i64,
+    /// Premium, administrative fee share and payer of the period before the
+    /// most recently paid one.  Premiums may be paid one period in advance,
+    /// so part of that period can still be unused on cancellation.
+    pub prior_premium: u64,
+    pub prior_premium_fee: u64,
+    pub prior_premium_payer: Pubkey,
+    /// Premium refund owed to `prior_premium_payer`, paid by
+    /// `close_membership`.
+    pub prior_refund_due: u64,
+}
+
+impl Member {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 1 + 8 + 8 + 32 + 8 + 8 + 1 + 8 + 8
+        + 8 + 32 + 8 + 8 + 8 + 32 + 8;
+
+    /// Counts a claim as open on the member and, if it was filed for one, on
+    /// the dependent.
+    pub fn open_claim(&mut self, dependent: Option<&mut Dependent>) {
+        self.open_claims = self.open_claims.checked_add(1).unwrap();
+        if let Some(dependent) = dependent {
+            dependent.open_claims = dependent.open_claims.checked_add(1).unwrap();
+        }
+    }
+
+    /// Reverses `open_claim` once a claim is paid or denied.
+    pub fn close_claim(&mut self, dependent: Option<&mut Dependent>) {
+        self.open_claims = self.open_claims.checked_sub(1).unwrap();
+        if let Some(dependent) = dependent {
+            dependent.open_claims = dependent.open_claims.checked_sub(1).unwrap();
+        }
+    }
+
+    /// Advances `benefit_year_start` to the benefit year containing `now`,
+    /// resetting the annual accumulators if a new year has begun.
+    pub fn roll_benefit_year(&mut self, now: i64) {
+        if now >= self.benefit_year_start.saturating_add(BENEFIT_YEAR) {
+            let years = (now - self.benefit_year_start) / BENEFIT_YEAR;
+            self.benefit_year_start += years * BENEFIT_YEAR;
+            self.year_paid = 0;
+            self.year_claim_count = 0;
+            self.year_deductible = 0;
+            self.year_out_of_pocket = 0;
+            self.family_year_deductible = 0;
+        }
+    }
+
+    /// Checks that a claim of `amount` submitted at `now` is within the
+    /// policy's coverage limit and that the member has benefits left under
+    /// the annual maximum, and counts it against the benefit year.
+    pub fn admit_claim(&mut self, policy: &Policy, amount: u64, now: i64) -> Result<()> {
+        require!(amount <= policy.coverage_limit, ApolloError::CoverageLimitExceeded);
+        self.roll_benefit_year(now);
+        require!(self.year_paid < policy.annual_max, ApolloError::AnnualMaximumReached);
+        self.year_claim_count = self.year_claim_count.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    /// Splits an approved claim `amount` for the subscriber, or for
+    /// `dependent` if given, between the member and the plan for the benefit
+    /// year containing `now`, updates the annual accumulators and returns the
+    /// plan's share.  The policy's in‑network or out‑of‑network cost sharing
+    /// applies depending on `in_network`; both count towards the same
+    /// accumulators.  The member pays, in order, what remains of the patient's
+    /// deductible (limited by what remains of the family deductible), the
+    /// copay and the coinsurance share of the rest, up to what remains of the
+    /// family's out‑of‑pocket maximum.  The plan's share is then capped at
+    /// what remains of the family's annual maximum.
+    pub fn apply_cost_sharing(
+        &mut self,
+        policy: &Policy,
+        now: i64,
+        amount: u64,
+        in_network: bool,
+        dependent: Option<&mut Dependent>,
+    ) -> u64 {
+        self.roll_benefit_year(now);
+        let cs = if in_network { &policy.cost_sharing } else { &policy.out_of_network };
+        let person_deductible = match &dependent {
+            Some(d) if d.benefit_year_start == self.benefit_year_start => d.year_deductible,
+            Some(_) => 0,
+            None => self.year_deductible,
+        };
+        let deductible = amount
+            .min(cs.deductible.saturating_sub(person_deductible))
+            .min(cs.family_deductible.saturating_sub(self.family_year_deductible));
+        let rest = amount - deductible;
+        let copay = rest.min(cs.copay);
+        let coinsurance = ((rest - copay) as u128 * cs.coinsurance_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        let member_share = (deductible + copay + coinsurance)
+            .min(cs.out_of_pocket_max.saturating_sub(self.year_out_of_pocket));
+        let paid = (amount - member_share).min(policy.annual_max.saturating_sub(self.year_paid));
+        let deductible_met = deductible.min(member_share);
+        match dependent {
+            Some(d) => {
+                d.benefit_year_start = self.benefit_year_start;
+                d.year_deductible = person_deductible.checked_add(deductible_met).unwrap();
+            }
+            None => self.year_deductible = self.year_deductible.checked_add(deductible_met).unwrap(),
+        }
+        self.family_year_deductible = self.family_year_deductible.checked_add(deductible_met).unwrap();
+        self.year_out_of_pocket = self.year_out_of_pocket.checked_add(member_share).unwrap();
+        self.year_paid = self.year_paid.checked_add(paid).unwrap();
+        paid
+    }
+
+    /// Records a premium of `amount`, including an administrative fee of
+    /// `fee`, paid by `payer` for the period ending at `paid_through`.  The
+    /// previous period becomes the prior one.
+    pub fn credit_premium(&mut self, payer: Pubkey, amount: u64, fee: u64, paid_through: i64) {
+        self.prior_premium = self.last_premium;
+        self.prior_premium_fee = self.last_premium_fee;
+        self.prior_premium_payer = self.last_premium_payer;
+        self.paid_through = paid_through;
+        self.total_premiums_paid = self.total_premiums_paid.checked_add(amount).unwrap();
+        self.last_premium = amount;
+        self.last_premium_fee = fee;
+        self.last_premium_payer = payer;
+    }
+
+    /// Moves the current period from the `old` premium to the `new` one at
+    /// `now`.  Returns the premium and fee differences prorated over the rest
+    /// of the period, positive when the member owes more.  The period's
+    /// recorded premium is repriced so that a later cancellation refunds what
+    /// was actually paid for it.
+    pub fn reprice_period(&mut self, old: &PremiumQuote, new: &PremiumQuote, now: i64) -> (i128, i128) {
+        let remaining = (self.paid_through - now).clamp(0, PREMIUM_PERIOD) as i128;
+        let prorate = |old: u64, new: u64| (new as i128 - old as i128) * remaining / PREMIUM_PERIOD as i128;
+        let reprice = |last: u64, old: u64, new: u64| (last as i128 + new as i128 - old as i128).max(0) as u64;
+        self.last_premium = reprice(self.last_premium, old.amount, new.amount);
+        self.last_premium_fee = reprice(self.last_premium_fee, old.fee, new.fee);
+        (prorate(old.amount, new.amount), prorate(old.fee, new.fee))
+    }
+
+    /// Checks that the member may add or remove dependents.  Dependent
+    /// premiums for the current period are charged to and refunded to the
+    /// member, and are recorded as part of the period's premium, so the
+    /// member must have paid the period themselves.  Employer groups split
+    /// premiums with the sponsor and are excluded for the same reason.
+    pub fn check_dependent_change(&self) -> Result<()> {
+        require!(self.employer_group == Pubkey::default(), ApolloError::InEmployerGroup);
+        require_keys_eq!(self.last_premium_payer, self.authority, ApolloError::PaidByThirdParty);
+        Ok(())
+    }
+
+    /// Returns true if a premium may be paid at `now`: coverage is paid at
+    /// most one period in advance.
+    pub fn premium_due(&self, now: i64) -> bool {
+        self.paid_through < now.saturating_add(PREMIUM_PERIOD)
+    }
+
+    /// Premiums refunded if the membership is cancelled at `now`.  The unused
+    /// part of the most recently paid period is refunded at that period's
+    /// premium, and any unused part of the period before it at the prior
+    /// premium, each to whoever paid it.  Refunds never exceed the premiums
+    /// paid so far.
+    pub fn cancellation_refund(&self, policy: &Policy, now: i64) -> CancellationRefund {
+        let unused = if self.is_covered(policy, now) { (self.paid_through - now).max(0) } else { 0 };
+        let last_unused = unused.min(PREMIUM_PERIOD) as u128;
+        let prior_unused = (unused - PREMIUM_PERIOD).clamp(0, PREMIUM_PERIOD) as u128;
+        let prorate = |amount: u64, unused: u128| (amount as u128 * unused / PREMIUM_PERIOD as u128) as u64;
+        let last = prorate(self.last_premium, last_unused).min(self.total_premiums_paid);
+        let prior = prorate(self.prior_premium, prior_unused).min(self.total_premiums_paid - last);
+        CancellationRefund {
+            last,
+            prior,
+            fees: prorate(self.last_premium_fee, last_unused) + prorate(self.prior_premium_fee, prior_unused),
+        }
+    }
+
+    /// Returns true if the member is active and `now` is no later than the
+    /// end of their paid period plus the policy's grace period.
+    pub fn is_covered(&self, policy: &Policy, now: i64) -> bool {
+        self.active && now <= self.paid_through.saturating_add(policy.grace_period)
+    }
+
+    /// Start of the period bought by a premium paid at `now`: the previous
+    /// due date while the member is covered, otherwise `now`.
+    pub fn next_period_start(&self, policy: &Policy, now: i64) -> i64 {
+        if self.is_covered(policy, now) {
+            self.paid_through
+        } else {
+            now
+        }
+    }
+
+    /// Returns true if `token` holds the member's proof‑of‑coverage NFT in
+    /// `holder`'s wallet.
+    pub fn proves_coverage(&self, token: &TokenAccount, holder: &Pubkey) -> bool {
+        token.mint == self.coverage_mint && token.owner == *holder && token.amount == 1
+    }
+}
+
+/// An employer sponsoring premiums for a roster of members under one policy.
+#[account]
+pub struct EmployerGroup {
+    pub sponsor: Pubkey,
+    /// The negotiated policy every roster member is enrolled in.
+    pub policy: Pubkey,
+    /// Share of each premium paid by the sponsor, in basis points.  The
+    /// remainder is the employee's contribution.
+    pub sponsor_share_bps: u16,
+    /// Member accounts on the roster.
+    pub members: Vec<Pubkey>,
+    /// End of the last period paid by `pay_group_premium`.
+    pub paid_through: i64,
+    pub bump: u8,
+}
+
+impl EmployerGroup {
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 4 + 32 * MAX_GROUP_MEMBERS + 8 + 1;
+
+    /// Splits a member's premium between the sponsor and the employee.
+    pub fn split(&self, quote: &PremiumQuote) -> GroupShares {
+        let sponsor_part =
+            |amount: u64| (amount as u128 * self.sponsor_share_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        let sponsor_share = sponsor_part(quote.amount);
+        GroupShares {
+            sponsor_share,
+            sponsor_fee: sponsor_part(quote.fee),
+            employee_share: quote.amount - sponsor_share,
+        }
+    }
+}
+
+/// Kind of off‑chain data an oracle feed reports.  There is one feed per
+/// kind.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FeedKind {
+    /// Provider credential checks.  A report's subject is a Provider
+    /// account and a nonzero value attests valid credentials.
+    ProviderCredential,
+    /// Qualifying life events.  A report's subject is a Member account and
+    /// a nonzero value attests a qualifying event.
+    LifeEvent,
+    /// Settlement of fiat payouts.
+    FiatSettlement,
+}
+
+/// A source of off‑chain data whose reports must be signed by `threshold`
+/// of its signers.
+#[account]
+pub struct OracleFeed {
+    pub kind: FeedKind,
+    /// Keys allowed to sign reports.  Only the first `signer_count` are used.
+    pub signers: [Pubkey; MAX_ORACLE_SIGNERS],
+    pub signer_count: u8,
+    /// Number of distinct signers required on each report.
+    pub threshold: u8,
+    /// Maximum age of a report, in seconds, for it to be posted or read.
+    pub max_staleness: i64,
+    pub bump: u8,
+}
+
+impl OracleFeed {
+    pub const LEN: usize = 8 + 1 + 32 * MAX_ORACLE_SIGNERS + 1 + 1 + 8 + 1;
+
+    /// Checks that a report observed at `observed_at` and signed by
+    /// `signatures` of the feed's signers may replace the report observed at
+    /// `previous`.  The report must not be from the future, must be within
+    /// `max_staleness` of `now` and must be newer than `previous`, so old
+    /// reports cannot be replayed.
+    pub fn accept_report(&self, observed_at: i64, previous: i64, signatures: u8, now: i64) -> Result<()> {
+        require!(observed_at <= now, ApolloError::InvalidParameter);
+        require!(now - observed_at <= self.max_staleness, ApolloError::StaleOracleReport);
+        require!(observed_at > previous, ApolloError::StaleOracleReport);
+        require!(signatures >= self.threshold, ApolloError::OracleQuorumNotMet);
+        Ok(())
+    }
+}
+
+/// The latest report of a feed on one subject.
+#[account]
+pub struct OracleReport {
+    pub feed: Pubkey,
+    /// The account the report is about, e.g. a Provider.
+    pub subject: Pubkey,
+    pub value: u64,
+    /// Hash of supporting off‑chain data, e.g. a settlement receipt.
+    pub data_hash: [u8; 32],
+    /// Time the data was observed, as signed by the oracles.
+    pub observed_at: i64,
+    pub bump: u8,
+}
+
+impl OracleReport {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 8 + 1;
+
+    /// The message oracles sign for a report: the feed and subject
+    /// addresses, the value, the data hash and the observation time, with
+    /// integers in little endian.
+    pub fn message(feed: &Pubkey, subject: &Pubkey, value: u64, data_hash: &[u8; 32], observed_at: i64) -> Vec<u8> {
+        [feed.as_ref(), subject.as_ref(), &value.to_le_bytes(), data_hash, &observed_at.to_le_bytes()].concat()
+    }
+}
+
+/// Negotiated pricing for one procedure category, maintained by the price
+/// oracle.
+#[account]
+pub struct FeeSchedule {
+    pub procedure_category: u16,
+    /// Most the plan allows for a procedure in this category.
+    pub allowed_amount: u64,
+    /// Market billed amounts at each of `FEE_SCHEDULE_PERCENTILES`.
+    pub billed_percentiles: [u64; FEE_PERCENTILES],
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl FeeSchedule {
+    pub const LEN: usize = 8 + 2 + 8 + 8 * FEE_PERCENTILES + 8 + 1;
+
+    /// Market billed amount at `percentile`, which must be one of
+    /// `FEE_SCHEDULE_PERCENTILES`.
+    pub fn billed_at(&self, percentile: u8) -> u64 {
+        let i = FEE_SCHEDULE_PERCENTILES.iter().position(|p| *p == percentile).unwrap();
+        self.billed_percentiles[i]
+    }
+}
+
+/// A healthcare provider that can be paid directly for claims.
+#[account]
+pub struct Provider {
+    pub authority: Pubkey,
+    /// Hash of the provider's National Provider Identifier.
+    pub npi_hash: [u8; 32],
+    /// Specialty code, e.g. a taxonomy group, defined off chain.
+    pub specialty: u16,
+    /// USDC account that receives direct payouts.
+    pub usdc_account: Pubkey,
+    /// Set once the provider's credentials have been attested.  Claims paid
+    /// to a verified provider are in network.
+    pub verified: bool,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl Provider {
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 32 + 1 + 8 + 1;
+}
+
+/// An off‑ramp operator approved by governance to settle fiat payouts.
+#[account]
+pub struct OffRampOperator {
+    pub authority: Pubkey,
+    /// USDC account that receives escrowed funds once a payout is settled.
+    pub usdc_account: Pubkey,
+    /// False if governance has suspended the operator.  Suspended operators
+    /// cannot take up new requests.
+    pub active: bool,
+    pub bump: u8,
+}
+
+impl OffRampOperator {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1;
+}
+
+/// Lifecycle of a fiat payout request.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FiatPayoutStatus {
+    /// Waiting for the escrow to be funded and an operator to take it up.
+    Pending,
+    /// An operator has taken up the request and is paying the member.
+    Claimed,
+    /// The settlement was attested and the escrow released to the operator.
+    Settled,
+    /// The request timed out and the escrow was returned to the member.
+    Refunded,
+}
+
+/// A member's request to receive a claim payout in fiat.
+#[account]
+pub struct FiatPayoutRequest {
+    pub claim: Pubkey,
+    /// Program‑owned token account holding the payout until settlement.
+    pub escrow: Pubkey,
+    /// The member's USDC account, which receives the escrow on timeout.
+    pub refund_account: Pubkey,
+    /// Hash of the member's off‑chain bank details.
+    pub bank_details_hash: [u8; 32],
+    /// The OffRampOperator that took up the request, if any.
+    pub operator: Pubkey,
+    pub status: FiatPayoutStatus,
+    pub claimed_at: i64,
+    /// Settlement receipt hash attested by the oracle feed.
+    pub receipt_hash: [u8; 32],
+    /// Wallet that paid for the request, which gets the escrow's rent back.
+    pub payer: Pubkey,
+    pub bump: u8,
+}
+
+impl FiatPayoutRequest {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 32 + 1 + 8 + 32 + 32 + 1;
+
+    /// Time after which the request may be refunded: `FIAT_CLAIM_WINDOW`
+    /// after the escrow was funded at `funded_at` while no operator has
+    /// taken it up, and `FIAT_SETTLEMENT_WINDOW` after an operator did.
+    pub fn deadline(&self, funded_at: i64) -> Result<i64> {
+        match self.status {
+            FiatPayoutStatus::Pending => Ok(funded_at.saturating_add(FIAT_CLAIM_WINDOW)),
+            FiatPayoutStatus::Claimed => Ok(self.claimed_at.saturating_add(FIAT_SETTLEMENT_WINDOW)),
+            _ => err!(ApolloError::InvalidFiatPayoutStatus),
+        }
+    }
+}
+
+/// A dependent covered under a subscriber's membership.  Dependents have no
+/// wallet of their own: the subscriber pays their premiums and files claims
+/// for them.
+#[account]
+pub struct Dependent {
+    /// The subscriber's Member account.
+    pub member: Pubkey,
+    pub id: u64,
+    /// Hash of the dependent's off‑chain identity record.
+    pub identity_hash: [u8; 32],
+    pub added_at: i64,
+    /// Benefit year to which `year_deductible` belongs.  Matches the
+    /// subscriber's benefit year once the dependent has had a paid claim in
+    /// it.
+    pub benefit_year_start: i64,
+    /// Deductible met by this dependent in `benefit_year_start`'s year.
+    pub year_deductible: u64,
+    pub bump: u8,
+    /// Claims filed for the dependent and not yet paid or denied.
+    pub open_claims: u64,
+}
+
+impl Dependent {
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 8 + 8 + 8 + 1 + 8;
+}
+
+#[account]
+pub struct Claim {
+    pub member: Pubkey,
+    /// Amount billed by the claimant.
+    pub amount: u64,
+    pub status: ClaimStatus,
+    pub submitted_at: i64,
+    pub updated_at: i64,
+    pub hash: [u8; 32],
+    pub bump: u8,
+    /// Portion of the approved amount left to the member by cost sharing
+    /// and the annual maximum.  Set when the claim is paid.
+    pub member_responsibility: u64,
+    /// Amount paid by the plan.  Set when the claim is paid.
+    pub paid_amount: u64,
+    /// The Dependent account the claim was filed for, or `None` if it is
+    /// for the subscriber.
+    pub dependent: Option<Pubkey>,
+    /// Number of appeals filed against a denial of this claim.
+    pub appeal_count: u8,
+    /// Hash of the off‑chain evidence supporting the latest appeal.
+    pub appeal_hash: [u8; 32],
+    /// Governance proposal deciding the latest appeal.
+    pub appeal_proposal: Pubkey,
+    /// Amount allowed on approval, before cost sharing.  At most `amount`.
+    pub approved_amount: u64,
+    /// Why the approved amount differs from the billed amount, or
+    /// `REASON_NONE`.
+    pub reason_code: u16,
+    /// Structured description of the service, if the claimant supplied one.
+    pub metadata: Option<ClaimMetadata>,
+    /// The Provider paid directly, or `None` if the claim reimburses the
+    /// claimant.
+    pub provider: Option<Pubkey>,
+    /// Whether in‑network cost sharing applies.
+    pub in_network: bool,
+    /// USDC account that receives the payout.
+    pub payee: Pubkey,
+    /// Allowed amount from the fee schedule checked at submission, if any.
+    /// Caps every payout of the claim.
+    pub allowed_amount: Option<u64>,
+    /// Set if the billed amount was above the flagging percentile of the fee
+    /// schedule.  Flagged claims are never paid automatically.
+    pub price_flagged: bool,
+    /// Slot whose hash, or that of the next produced slot, seeds the
+    /// claim's review panel.  Committed when the claim enters review.
+    pub review_seed_slot: u64,
+    /// `Member.enrollment_id` of the enrollment the claim was filed under.
+    pub enrollment_id: u64,
+}
+
+impl Claim {
+    /// Returns true if the claims authority may resolve the claim: its panel
+    /// escalated it, or it awaits review but `registry`, which may not have
+    /// been created yet, cannot staff a panel for `claimant`.
+    pub fn awaits_authority(&self, registry: &AccountInfo, claimant: &Pubkey, program_id: &Pubkey) -> Result<bool> {
+        Ok(match self.status {
+            ClaimStatus::Escalated => true,
+            ClaimStatus::NeedsReview if registry.owner == program_id => {
+                let registry = ReviewerRegistry::try_deserialize(&mut &registry.try_borrow_data()?[..])?;
+                registry.eligible(claimant).len() < PANEL_SIZE
+            }
+            ClaimStatus::NeedsReview => true,
+            _ => false,
+        })
+    }
+
+    pub const LEN: usize = 8 + 32 + 8 + 1 + 8 + 8 + 32 + 1 + 8 + 8 + 1 + 32 + 1 + 32 + 32 + 8 + 2 + 1 + ClaimMetadata::LEN
+        + 1 + 32 + 1 + 32 + 1 + 8 + 1 + 8 + 8;
+
+    /// Returns true if the claim was filed under enrollment `enrollment_id`
+    /// of the Member account at `member`.  The Member address is reused when
+    /// a wallet enrolls again, so claims from a closed enrollment must not be
+    /// acted on against the new one.
+    pub fn belongs_to(&self, member: &Pubkey, enrollment_id: u64) -> bool {
+        self.member == *member && self.enrollment_id == enrollment_id
+    }
+
+    /// Moves a denied claim to `Appealed` under `proposal`, allowing at most
+    /// `MAX_APPEALS` appeals per claim.
+    pub fn file_appeal(&mut self, evidence_hash: [u8; 32], proposal: Pubkey, now: i64) -> Result<()> {
+        require!(self.status == ClaimStatus::Denied, ApolloError::InvalidClaimStatus);
+        require!(self.appeal_count < MAX_APPEALS, ApolloError::AppealLimitReached);
+        self.status = ClaimStatus::Appealed;
+        self.appeal_count += 1;
+        self.appeal_hash = evidence_hash;
+        self.appeal_proposal = proposal;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Caps `amount` at the fee schedule's allowed amount.  Returns the
+    /// capped amount and `reason_code`, or `REASON_FEE_SCHEDULE` if the cap
+    /// applied.
+    pub fn apply_fee_schedule(&self, amount: u64, reason_code: u16) -> (u64, u16) {
+        match self.allowed_amount {
+            Some(allowed) if allowed < amount => (allowed, REASON_FEE_SCHEDULE),
+            _ => (amount, reason_code),
+        }
+    }
+
+    /// Records the approval of `approved` of the billed amount, of which the
+    /// plan pays `paid`.  A claim approved for less than the billed amount is
+    /// marked `PartiallyApproved` with `reason_code`; one approved in full is
+    /// marked `Paid` with `REASON_NONE`.
+    pub fn record_approval(&mut self, approved: u64, paid: u64, reason_code: u16) {
+        self.approved_amount = approved;
+        self.paid_amount = paid;
+        self.member_responsibility = approved - paid;
+        if approved < self.amount {
+            self.status = ClaimStatus::PartiallyApproved;
+            self.reason_code = reason_code;
+        } else {
+            self.status = ClaimStatus::Paid;
+            self.reason_code = REASON_NONE;
+        }
+    }
+}
+
+#[account]
+pub struct Stake {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub start_timestamp: i64,
+    pub bump: u8,
+    /// Ring buffer of balance checkpoints used to look up historical voting
+    /// power.  `checkpoint_count` is the total number ever written; the next
+    /// slot to overwrite is `checkpoint_count % STAKE_CHECKPOINTS`.
+    pub checkpoints: [Checkpoint; STAKE_CHECKPOINTS],
+    pub checkpoint_count: u64,
+    /// Integral of `amount` over time (APH base units × seconds) up to
+    /// `last_update_ts`.
+    pub cumulative_balance_seconds: u128,
+    pub last_update_ts: i64,
+    /// Ring buffer of TWAB observations, written on every balance change.
+    /// Indexed the same way as `checkpoints`.
+    pub observations: [TwabObservation; TWAB_OBSERVATIONS],
+    pub observation_count: u64,
+    /// Set while the staker is in the reviewer registry.  The stake cannot
+    /// be withdrawn until they deregister.
+    pub reviewer: bool,
+}
+
+impl Stake {
+    pub const LEN: usize = 8
+        + 32
+        + 8
+        + 8
+        + 1
+        + Checkpoint::LEN * STAKE_CHECKPOINTS
+        + 8
+        + 16
+        + 8
+        + TwabObservation::LEN * TWAB_OBSERVATIONS
+        + 8
+        + 1;
+
+    /// Changes the staked balance, accruing the TWAB accumulator for the
+    /// previous balance and recording both a voting power checkpoint and a
+    /// TWAB observation for the new one.  All balance changes must go through
+    /// this method.
+    pub fn set_amount(&mut self, amount: u64, clock: &Clock) {
+        let now = clock.unix_timestamp;
+        let elapsed = now.saturating_sub(self.last_update_ts).max(0) as u128;
+        self.cumulative_balance_seconds = self
+            .cumulative_balance_seconds
+            .checked_add((self.amount as u128).checked_mul(elapsed).unwrap())
+            .unwrap();
+        self.last_update_ts = now;
+        self.amount = amount;
+        self.record_checkpoint(clock.slot);
+        self.record_observation();
+    }
+
+    /// Appends the current accumulator state to the observation ring.
+    /// Multiple updates at the same timestamp overwrite a single observation.
+    fn record_observation(&mut self) {
+        let obs = TwabObservation {
+            timestamp: self.last_update_ts,
+            cumulative: self.cumulative_balance_seconds,
+            balance: self.amount,
+        };
+        if self.observation_count > 0 {
+            let last = ((self.observation_count - 1) % TWAB_OBSERVATIONS as u64) as usize;
+            if self.observations[last].timestamp == obs.timestamp {
+                self.observations[last] = obs;
+                return;
+            }
+        }
+        let next = (self.observation_count % TWAB_OBSERVATIONS as u64) as usize;
+        self.observations[next] = obs;
+        self.observation_count = self.observation_count.checked_add(1).unwrap();
+    }
+
+    /// Returns the balance-seconds accumulated up to `timestamp`, or `None`
+    /// if the timestamp predates the oldest retained observation.
+    pub fn cumulative_at(&self, timestamp: i64) -> Option<u128> {
+        let retained = self.observation_count.min(TWAB_OBSERVATIONS as u64);
+        for i in 0..retained {
+            let idx = ((self.observation_count - 1 - i) % TWAB_OBSERVATIONS as u64) as usize;
+            let obs = &self.observations[idx];
+            if obs.timestamp <= timestamp {
+                let elapsed = (timestamp - obs.timestamp) as u128;
+                return obs.cumulative.checked_add((obs.balance as u128).checked_mul(elapsed)?);
+            }
+        }
+        // Before the first observation nothing was staked, unless older
+        // observations have been overwritten.
+        if self.observation_count <= TWAB_OBSERVATIONS as u64 {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    /// Time‑weighted average balance over `[start, end]`.  If `start`
+    /// predates the retained observations, the window starts at the oldest
+    /// one instead, so a long‑standing balance is not lost when the ring
+    /// wraps.  Returns `None` if the window is empty.
+    pub fn twab(&self, start: i64, end: i64) -> Option<u64> {
+        let start = start.max(self.oldest_observation_at());
+        if end <= start {
+            return None;
+        }
+        let delta = self.cumulative_at(end)?.checked_sub(self.cumulative_at(start)?)?;
+        u64::try_from(delta / (end - start) as u128).ok()
+    }
+
+    /// Timestamp of the oldest retained observation, or `i64::MIN` if none
+    /// has been overwritten yet.
+    fn oldest_observation_at(&self) -> i64 {
+        if self.observation_count <= TWAB_OBSERVATIONS as u64 {
+            i64::MIN
+        } else {
+            self.observations[(self.observation_count % TWAB_OBSERVATIONS as u64) as usize].timestamp
+        }
+    }
+
+    /// Records the current `amount` as of `slot`.  Multiple updates within
+    /// the same slot overwrite a single checkpoint.
+    pub fn record_checkpoint(&mut self, slot: u64) {
+        if self.checkpoint_count > 0 {
+            let last = ((self.checkpoint_count - 1) % STAKE_CHECKPOINTS as u64) as usize;
+            if self.checkpoints[last].slot == slot {
+                self.checkpoints[last].amount = self.amount;
+                return;
+            }
+        }
+        let next = (self.checkpoint_count % STAKE_CHECKPOINTS as u64) as usize;
+        self.checkpoints[next] = Checkpoint { slot, amount: self.amount };
+        self.checkpoint_count = self.checkpoint_count.checked_add(1).unwrap();
+    }
+
+    /// Returns the staked balance at the end of the last slot before
+    /// `snapshot_slot`.  Balances changed in the snapshot slot itself are
+    /// ignored, so stake cannot be added in the same slot as a proposal to
+    /// gain votes.  If the snapshot predates the oldest retained checkpoint
+    /// the balance is unknown and zero is returned.
+    pub fn voting_power_at(&self, snapshot_slot: u64) -> u64 {
+        let retained = self.checkpoint_count.min(STAKE_CHECKPOINTS as u64);
+        // Walk backwards from the newest checkpoint.
+        for i in 0..retained {
+            let idx = ((self.checkpoint_count - 1 - i) % STAKE_CHECKPOINTS as u64) as usize;
+            let cp = &self.checkpoints[idx];
+            if cp.slot < snapshot_slot {
+                return cp.amount;
+            }
+        }
+        0
+    }
+}
+
+/// Staked balance of an account as of a given slot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct Checkpoint {
+    pub slot: u64,
+    pub amount: u64,
+}
+
+impl Checkpoint {
+    pub const LEN: usize = 8 + 8;
 }
 
-/*
- * Account types
- */
+/// Snapshot of a Stake's TWAB accumulator taken when its balance changed.
+/// `balance` is the staked amount from `timestamp` until the next observation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TwabObservation {
+    pub timestamp: i64,
+    pub cumulative: u128,
+    pub balance: u64,
+}
+
+impl TwabObservation {
+    pub const LEN: usize = 8 + 16 + 8;
+}
 
 #[account]
-pub struct Config {
-    pub authority: Pubkey,
-    pub usdc_mint: Pubkey,
-    pub aph_mint: Pubkey,
-    pub fast_claim_threshold: u64,
-    pub next_policy_id: u64,
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub description_hash: [u8; 32],
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    /// Voting power is measured as of the end of the slot before this one.
+    pub snapshot_slot: u64,
+    /// Quorum captured from the config when the proposal was created.
+    pub quorum_votes: u64,
+    pub for_votes: u64,
+    pub against_votes: u64,
+    pub status: ProposalStatus,
+    pub executed_at: i64,
     pub bump: u8,
 }
 
-impl Config {
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+impl Proposal {
+    pub const LEN: usize = 8 + 8 + 32 + ProposalAction::MAX_LEN + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 1;
+
+    /// Returns true if voting has closed and the proposal reached both quorum
+    /// and a simple majority of the votes cast.
+    pub fn has_passed(&self, now: i64) -> bool {
+        let total = self.for_votes.saturating_add(self.against_votes);
+        now > self.voting_ends_at && total >= self.quorum_votes && self.for_votes > self.against_votes
+    }
+
+    /// Returns true once `EXECUTION_WINDOW` has elapsed since voting closed.
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.voting_ends_at.saturating_add(EXECUTION_WINDOW)
+    }
+
+    /// Checks that the proposal is still active, has passed and is within its
+    /// execution window.  Every executor calls this before applying the
+    /// proposal's action.
+    pub fn require_executable(&self, now: i64) -> Result<()> {
+        require!(self.status == ProposalStatus::Active, ApolloError::InvalidProposalStatus);
+        require!(self.has_passed(now), ApolloError::ProposalNotPassed);
+        require!(!self.is_expired(now), ApolloError::ProposalExpired);
+        Ok(())
+    }
 }
 
 #[account]
-pub struct Policy {
-    pub creator: Pubkey,
-    pub monthly_premium: u64,
-    pub coverage_limit: u64,
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub support: bool,
     pub bump: u8,
 }
 
-impl Policy {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
+impl VoteRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1;
+}
+
+/// Actions that a governance proposal can carry out once it passes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalAction {
+    /// Replace the claims authority stored in the config.
+    SetAuthority { authority: Pubkey },
+    /// Change the maximum claim amount that is paid automatically.
+    SetFastClaimThreshold { threshold: u64 },
+    /// Change the governance parameters used by future proposals.
+    SetGovernanceParams {
+        voting_period: i64,
+        quorum_votes: u64,
+        proposal_threshold: u64,
+    },
+    /// Replace the staking discount tiers and the TWAB window used to
+    /// determine eligibility.
+    SetDiscountTiers {
+        tiers: [DiscountTier; DISCOUNT_TIERS],
+        twab_window: i64,
+    },
+    /// Change the parameters of the decentralized claims review process.
+    SetReviewParams {
+        min_reviewer_stake: u64,
+        review_bond_bps: u16,
+        review_period: i64,
+        review_slash_bps: u16,
+        review_reward_bps: u16,
+        review_fee_bps: u16,
+    },
+    /// Set the open enrollment window and the life event attestor used by
+    /// `change_policy`.
+    SetEnrollmentParams {
+        open_enrollment_start: i64,
+        open_enrollment_end: i64,
+        life_event_attestor: Pubkey,
+    },
+    /// Create a new policy.  Executed by `create_policy`.
+    CreatePolicy { terms: PolicyTerms },
+    /// Publish a new version of `policy` taking effect at `effective_at`.
+    /// Executed by `update_policy`.
+    UpdatePolicy {
+        policy: Pubkey,
+        effective_at: i64,
+        terms: PolicyTerms,
+    },
+    /// Close `policy` to new enrollments.  Executed by `deactivate_policy`.
+    DeactivatePolicy { policy: Pubkey },
+    /// Overturn the denial of `claim` and pay it.  Created by `appeal_claim`
+    /// and executed by `resolve_appeal`.
+    OverturnDenial { claim: Pubkey },
+    /// Replace the signer that attests provider credentials.
+    SetProviderAttestor { attestor: Pubkey },
+    /// Set or clear the verified flag of `provider`.  Executed by
+    /// `verify_provider`.
+    VerifyProvider { provider: Pubkey, verified: bool },
+    /// Replace the price oracle that maintains fee schedules and the
+    /// percentile of billed amounts above which claims are flagged.
+    SetPricingParams { price_oracle: Pubkey, price_flag_percentile: u8 },
+    /// Register an off‑ramp operator or update it.  Executed by
+    /// `set_off_ramp_operator`.
+    SetOffRampOperator {
+        authority: Pubkey,
+        usdc_account: Pubkey,
+        active: bool,
+    },
+    /// Create the oracle feed of `kind` or replace its parameters.  Executed
+    /// by `configure_oracle_feed`.
+    ConfigureOracleFeed {
+        kind: FeedKind,
+        signers: [Pubkey; MAX_ORACLE_SIGNERS],
+        signer_count: u8,
+        threshold: u8,
+        max_staleness: i64,
+    },
+}
+
+impl ProposalAction {
+    /// Serialized size of the largest variant (`ConfigureOracleFeed`),
+    /// including the discriminant.
+    pub const MAX_LEN: usize = 1 + 1 + 32 * MAX_ORACLE_SIGNERS + 1 + 1 + 8;
+
+    /// Whether the action is applied by a dedicated executor instruction
+    /// rather than by `execute_proposal`.
+    pub fn has_executor(&self) -> bool {
+        matches!(
+            self,
+            ProposalAction::CreatePolicy { .. }
+                | ProposalAction::UpdatePolicy { .. }
+                | ProposalAction::DeactivatePolicy { .. }
+                | ProposalAction::OverturnDenial { .. }
+                | ProposalAction::VerifyProvider { .. }
+                | ProposalAction::ConfigureOracleFeed { .. }
+                | ProposalAction::SetOffRampOperator { .. }
+        )
+    }
+
+    /// Checks that the action's parameters are well formed.
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            ProposalAction::SetAuthority { .. } | ProposalAction::SetFastClaimThreshold { .. } => {}
+            ProposalAction::SetReviewParams {
+                review_bond_bps,
+                review_period,
+                review_slash_bps,
+                review_reward_bps,
+                review_fee_bps,
+                ..
+            } => {
+                for bps in [review_bond_bps, review_slash_bps, review_reward_bps, review_fee_bps] {
+                    require!(bps as u64 <= BPS_DENOMINATOR, ApolloError::InvalidParameter);
+                }
+                require!(review_period > 0, ApolloError::InvalidParameter);
+            }
+            ProposalAction::SetGovernanceParams { voting_period, quorum_votes, .. } => {
+                require!(voting_period > 0, ApolloError::InvalidParameter);
+                require!(quorum_votes > 0, ApolloError::InvalidParameter);
+            }
+            ProposalAction::SetDiscountTiers { tiers, twab_window } => {
+                require!(twab_window > 0, ApolloError::InvalidParameter);
+                for (i, tier) in tiers.iter().enumerate() {
+                    require!(tier.rebate_bps as u64 <= BPS_DENOMINATOR, ApolloError::InvalidParameter);
+                    if i > 0 {
+                        let prev = &tiers[i - 1];
+                        require!(tier.min_stake > prev.min_stake, ApolloError::InvalidParameter);
+                        require!(tier.rebate_bps >= prev.rebate_bps, ApolloError::InvalidParameter);
+                    }
+                }
+            }
+            ProposalAction::SetEnrollmentParams { open_enrollment_start, open_enrollment_end, .. } => {
+                require!(open_enrollment_end >= open_enrollment_start, ApolloError::InvalidParameter);
+            }
+            ProposalAction::CreatePolicy { terms } | ProposalAction::UpdatePolicy { terms, .. } => terms.validate()?,
+            ProposalAction::DeactivatePolicy { .. }
+            | ProposalAction::SetProviderAttestor { .. }
+            | ProposalAction::VerifyProvider { .. }
+            | ProposalAction::SetOffRampOperator { .. } => {}
+            // Appeals are only opened by `appeal_claim`.
+            ProposalAction::OverturnDenial { .. } => return err!(ApolloError::InvalidProposalAction),
+            ProposalAction::ConfigureOracleFeed { signers, signer_count, threshold, max_staleness, .. } => {
+                require!(signer_count as usize <= MAX_ORACLE_SIGNERS, ApolloError::InvalidParameter);
+                require!(threshold > 0 && threshold <= signer_count, ApolloError::InvalidParameter);
+                require!(max_staleness > 0, ApolloError::InvalidParameter);
+                let active = &signers[..signer_count as usize];
+                for (i, signer) in active.iter().enumerate() {
+                    require!(*signer != Pubkey::default(), ApolloError::InvalidParameter);
+                    require!(!active[..i].contains(signer), ApolloError::InvalidParameter);
+                }
+            }
+            ProposalAction::SetPricingParams { price_flag_percentile, .. } => {
+                require!(
+                    FEE_SCHEDULE_PERCENTILES.contains(&price_flag_percentile),
+                    ApolloError::InvalidParameter
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Lifecycle of a governance proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    /// Voting is open, or has closed but the proposal has not been finalized.
+    Active,
+    /// The proposal failed to reach quorum or a majority, or was not
+    /// executed within `EXECUTION_WINDOW` of passing.
+    Defeated,
+    /// The proposal passed and its action has been applied.
+    Executed,
 }
 
+/// Registry of stakers eligible to be drawn onto claims review panels.
 #[account]
-pub struct Member {
-    pub authority: Pubkey,
-    pub policy: Pubkey,
-    pub active: bool,
-    pub join_timestamp: i64,
-    pub claim_count: u64,
+pub struct ReviewerRegistry {
+    pub reviewers: Vec<ReviewerEntry>,
     pub bump: u8,
 }
 
-impl Member {
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 1;
+impl ReviewerRegistry {
+    pub const LEN: usize = 8 + 4 + ReviewerEntry::LEN * MAX_REVIEWERS + 1;
+
+    /// Reviewers who may sit on a panel for a claim by `claimant`.
+    pub fn eligible(&self, claimant: &Pubkey) -> Vec<ReviewerEntry> {
+        self.reviewers.iter().filter(|e| e.authority != *claimant && e.weight > 0).copied().collect()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ReviewerEntry {
+    pub authority: Pubkey,
+    /// Selection weight: the reviewer's stake when they last registered.
+    pub weight: u64,
 }
 
+impl ReviewerEntry {
+    pub const LEN: usize = 32 + 8;
+}
+
+/// Reputation and history of a claims reviewer.
 #[account]
-pub struct Claim {
-    pub member: Pubkey,
-    pub amount: u64,
-    pub status: ClaimStatus,
-    pub submitted_at: i64,
-    pub updated_at: i64,
-    pub hash: [u8; 32],
+pub struct Reviewer {
+    pub authority: Pubkey,
+    /// Reputation score between 0 and `MAX_REPUTATION`.
+    pub reputation: u16,
+    /// Number of bonds currently escrowed.
+    pub active_bonds: u32,
+    /// Total APH currently escrowed across active bonds.
+    pub bonded_amount: u64,
+    /// Reviews in which the reviewer voted with the final outcome.
+    pub reviews_with_consensus: u32,
+    /// Reviews in which the reviewer voted against the final outcome.
+    pub reviews_against_consensus: u32,
+    /// Reviews in which the reviewer was seated but did not vote.
+    pub reviews_missed: u32,
     pub bump: u8,
 }
 
-impl Claim {
-    pub const LEN: usize = 8 + 32 + 8 + 1 + 8 + 8 + 32 + 1;
+impl Reviewer {
+    pub const LEN: usize = 8 + 32 + 2 + 4 + 8 + 4 + 4 + 4 + 1;
+
+    /// Scaling applied to the base bond: high‑reputation reviewers post 75%,
+    /// low‑reputation reviewers 150%, everyone else the base amount.
+    pub fn bond_multiplier_bps(&self) -> u64 {
+        if self.reputation >= HIGH_REPUTATION {
+            7_500
+        } else if self.reputation < LOW_REPUTATION {
+            15_000
+        } else {
+            BPS_DENOMINATOR
+        }
+    }
+
+    /// APH bond owed for sitting on the panel of a claim of `claim_amount`:
+    /// `bond_bps` of the amount scaled by `bond_multiplier_bps`.  Never zero,
+    /// so that every posted seat is detectable.
+    pub fn bond_for(&self, claim_amount: u64, bond_bps: u16) -> u64 {
+        let base = claim_amount as u128 * bond_bps as u128 / BPS_DENOMINATOR as u128;
+        let bond = (base * self.bond_multiplier_bps() as u128 / BPS_DENOMINATOR as u128) as u64;
+        bond.max(1)
+    }
+
+    /// Updates the reviewer's history and reputation for a settled seat.
+    pub fn record_verdict(&mut self, verdict: SeatVerdict) {
+        match verdict {
+            SeatVerdict::Consensus => {
+                self.reviews_with_consensus = self.reviews_with_consensus.checked_add(1).unwrap();
+                self.reputation = self.reputation.saturating_add(REPUTATION_CONSENSUS_GAIN).min(MAX_REPUTATION);
+            }
+            SeatVerdict::Dissent => {
+                self.reviews_against_consensus = self.reviews_against_consensus.checked_add(1).unwrap();
+                self.reputation = self.reputation.saturating_sub(REPUTATION_DISSENT_PENALTY);
+            }
+            SeatVerdict::Missed => {
+                self.reviews_missed = self.reviews_missed.checked_add(1).unwrap();
+                self.reputation = self.reputation.saturating_sub(REPUTATION_MISSED_PENALTY);
+            }
+            SeatVerdict::Unjudged => {}
+        }
+    }
 }
 
+/// The reviewers drawn to adjudicate a single claim.
 #[account]
-pub struct Stake {
-    pub authority: Pubkey,
-    pub amount: u64,
-    pub start_timestamp: i64,
+pub struct ReviewPanel {
+    pub claim: Pubkey,
+    pub reviewers: [Pubkey; PANEL_SIZE],
+    pub selected_at: i64,
     pub bump: u8,
+    /// APH bond posted by each seat, zero if not yet posted.
+    pub bonds: [u64; PANEL_SIZE],
+    /// Votes must be cast before this timestamp.
+    pub deadline: i64,
+    /// Vote cast by each seat, if any.
+    pub votes: [Option<ReviewVote>; PANEL_SIZE],
+    pub outcome: ReviewOutcome,
+    /// Amount paid to the claimant if the panel approved the claim.
+    pub approved_amount: u64,
+    /// Whether each seat's bond has been settled.
+    pub settled: [bool; PANEL_SIZE],
+    /// Reviewer who created the bond vault by posting the first bond, and
+    /// receives its rent when it is closed.
+    pub vault_payer: Pubkey,
 }
 
-impl Stake {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
+impl ReviewPanel {
+    pub const LEN: usize = 8
+        + 32
+        + 32 * PANEL_SIZE
+        + 8
+        + 1
+        + 8 * PANEL_SIZE
+        + 8
+        + (1 + ReviewVote::MAX_LEN) * PANEL_SIZE
+        + 1
+        + 8
+        + PANEL_SIZE
+        + 32;
+
+    /// Decides the panel's outcome for a claim of `claimed` at `now` from the
+    /// votes cast so far, along with the lower median of the approving votes
+    /// if it approved.  A majority of the panel decides at any time.  After
+    /// the deadline a plurality decides if at least `PANEL_MAJORITY` seats
+    /// voted, and the claim is escalated otherwise.  Fails while the panel
+    /// can still decide.
+    pub fn decide(&self, claimed: u64, now: i64) -> Result<(ReviewOutcome, u64)> {
+        let mut approved: Vec<u64> =
+            self.votes.iter().flatten().filter_map(|v| v.approved_amount(claimed)).collect();
+        let denials = self.votes.iter().flatten().filter(|v| **v == ReviewVote::Deny).count();
+        let expired = now > self.deadline;
+        let quorate = expired && approved.len() + denials >= PANEL_MAJORITY;
+        if approved.len() >= PANEL_MAJORITY || (quorate && approved.len() > denials) {
+            approved.sort_unstable();
+            Ok((ReviewOutcome::Approved, approved[(approved.len() - 1) / 2]))
+        } else if denials >= PANEL_MAJORITY || (quorate && denials > approved.len()) {
+            Ok((ReviewOutcome::Denied, 0))
+        } else if expired {
+            Ok((ReviewOutcome::Escalated, 0))
+        } else {
+            err!(ApolloError::ReviewStillOpen)
+        }
+    }
+
+    /// Returns the seat index of `reviewer` on this panel.
+    pub fn seat_of(&self, reviewer: &Pubkey) -> Result<usize> {
+        self.reviewers
+            .iter()
+            .position(|r| r == reviewer)
+            .ok_or_else(|| error!(ApolloError::NotOnPanel))
+    }
+
+    /// How the vote cast by `seat` compares with the panel's outcome.
+    pub fn verdict(&self, seat: usize) -> SeatVerdict {
+        match (self.votes[seat], self.outcome) {
+            (None, _) => SeatVerdict::Missed,
+            (Some(vote), ReviewOutcome::Approved) if vote != ReviewVote::Deny => SeatVerdict::Consensus,
+            (Some(ReviewVote::Deny), ReviewOutcome::Denied) => SeatVerdict::Consensus,
+            (Some(_), ReviewOutcome::Approved | ReviewOutcome::Denied) => SeatVerdict::Dissent,
+            (Some(_), _) => SeatVerdict::Unjudged,
+        }
+    }
+
+    /// Marks `seat` as settled and returns true if every seat that posted a
+    /// bond is now settled, so that the bond vault can be closed.
+    pub fn settle_seat(&mut self, seat: usize) -> Result<bool> {
+        require!(!self.settled[seat], ApolloError::AlreadySettled);
+        self.settled[seat] = true;
+        Ok(self.bonds.iter().zip(self.settled).all(|(bond, settled)| *bond == 0 || settled))
+    }
+}
+
+/// A panel reviewer's decision on a claim.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewVote {
+    /// Pay the full claimed amount.
+    Approve,
+    /// Pay nothing.
+    Deny,
+    /// Pay a lower amount than was claimed.
+    Partial { amount: u64 },
+}
+
+impl ReviewVote {
+    pub const MAX_LEN: usize = 1 + 8;
+
+    /// The amount this vote would pay for a claim of `claimed`, or `None` for
+    /// a denial.
+    pub fn approved_amount(&self, claimed: u64) -> Option<u64> {
+        match *self {
+            ReviewVote::Approve => Some(claimed),
+            ReviewVote::Deny => None,
+            ReviewVote::Partial { amount } => Some(amount),
+        }
+    }
+}
+
+/// Result of a panel review.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewOutcome {
+    /// The panel has not been finalized.
+    Pending,
+    /// The claim was approved and paid.
+    Approved,
+    /// The claim was denied.
+    Denied,
+    /// The panel failed to decide and the claim was escalated to the
+    /// authority.
+    Escalated,
+}
+
+/// How a panel seat's vote compares with the panel's outcome.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SeatVerdict {
+    /// Voted with the outcome.
+    Consensus,
+    /// Voted against the outcome.
+    Dissent,
+    /// Did not vote.
+    Missed,
+    /// Voted, but the panel escalated without a decision.
+    Unjudged,
 }
 
 /// Claim status enumeration.  The number of variants is small and fits in a
@@ -512,12 +4890,111 @@ pub enum ClaimStatus {
     /// Claim was submitted and awaits processing.  In v1 this state is not
     /// used because claims are either auto‑paid or marked for review.
     Submitted,
-    /// Claim is automatically approved and has been paid.
+    /// Claim was approved for the billed amount and has been paid.
     Paid,
-    /// Claim requires manual review and approval by the authority.
+    /// Claim awaits a review panel, or the claims authority if too few
+    /// reviewers are registered to draw one.
     NeedsReview,
     /// Claim was manually denied.
     Denied,
+    /// A review panel has been selected and is adjudicating the claim.
+    UnderReview,
+    /// The member has appealed a denial and governance is voting on it.
+    Appealed,
+    /// Claim was approved for less than the billed amount and the allowed
+    /// amount has been paid.
+    PartiallyApproved,
+    /// The review panel missed its deadline without a quorum or a decision,
+    /// and the claims authority must resolve the claim.
+    Escalated,
+}
+
+/// Kind of care a claim is for.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimType {
+    Medical,
+    Pharmacy,
+    Dental,
+}
+
+/// Structured claim fields used for reporting and rule‑based adjudication.
+/// Nothing here identifies the patient: procedure and diagnosis codes are
+/// recorded only as coarse categories, with the full codes committed to by
+/// a salted hash that can be checked against the off‑chain record.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimMetadata {
+    pub claim_type: ClaimType,
+    /// First and last day of service, as unix timestamps.
+    pub service_start: i64,
+    pub service_end: i64,
+    /// Procedure category, e.g. the CPT section of the main procedure, or 0
+    /// if not given.
+    pub procedure_category: u16,
+    /// Diagnosis category, e.g. the ICD‑10 chapter of the main diagnosis, or
+    /// 0 if not given.
+    pub diagnosis_category: u16,
+    /// Salted hash of the full procedure and diagnosis codes.
+    pub codes_hash: [u8; 32],
+    /// The provider that rendered the service, if known.
+    pub provider: Option<Pubkey>,
+}
+
+impl ClaimMetadata {
+    pub const LEN: usize = 1 + 8 + 8 + 2 + 2 + 32 + 1 + 32;
+
+    /// Checks that the service dates are ordered and fall between
+    /// `covered_from` and `now`.
+    pub fn validate(&self, covered_from: i64, now: i64) -> Result<()> {
+        require!(self.service_start <= self.service_end, ApolloError::InvalidServiceDates);
+        require!(self.service_start >= covered_from, ApolloError::InvalidServiceDates);
+        require!(self.service_end <= now, ApolloError::InvalidServiceDates);
+        Ok(())
+    }
+}
+
+/*
+ * Events
+ */
+
+/// Emitted whenever a premium is collected.
+#[event]
+pub struct PremiumPaid {
+    pub payer: Pubkey,
+    /// Member account credited with the payment.
+    pub member: Pubkey,
+    pub policy: Pubkey,
+    /// Index of the discount tier applied, if the member qualified for one.
+    pub tier: Option<u8>,
+    pub fee_discount: u64,
+    pub amount: u64,
+    /// Start and end of the coverage period bought by this payment.
+    pub period_start: i64,
+    pub paid_through: i64,
+}
+
+/// Emitted when a member switches policies.
+#[event]
+pub struct PolicyChanged {
+    pub member: Pubkey,
+    pub old_policy: Pubkey,
+    pub new_policy: Pubkey,
+    /// True if the change was made outside open enrollment on an attested
+    /// life event.
+    pub life_event: bool,
+    /// Prorated premium charged (positive) or refunded (negative).
+    pub premium_delta: i64,
+}
+
+/// Emitted when a fiat payout is settled and its escrow released to the
+/// off‑ramp operator.  The request account is closed, so this is the
+/// lasting record of the settlement receipt.
+#[event]
+pub struct FiatPayoutSettled {
+    pub request: Pubkey,
+    pub claim: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub receipt_hash: [u8; 32],
 }
 
 /*
@@ -534,4 +5011,914 @@ pub enum ApolloError {
     InsufficientPoolBalance,
     #[msg("Invalid claim status for this operation")]
     InvalidClaimStatus,
+    #[msg("Invalid proposal status for this operation")]
+    InvalidProposalStatus,
+    #[msg("Proposal action cannot be executed by this instruction")]
+    InvalidProposalAction,
+    #[msg("Proposal has not passed")]
+    ProposalNotPassed,
+    #[msg("Voting on this proposal has closed")]
+    VotingClosed,
+    #[msg("Voting on this proposal is still open")]
+    VotingStillOpen,
+    #[msg("Insufficient staked APH for this governance action")]
+    InsufficientVotingPower,
+    #[msg("TWAB window reaches past the retained stake history")]
+    TwabWindowUnavailable,
+    #[msg("Insufficient staked APH for this action")]
+    InsufficientStake,
+    #[msg("The reviewer registry is full")]
+    ReviewerRegistryFull,
+    #[msg("Reviewer is not registered")]
+    ReviewerNotRegistered,
+    #[msg("Not enough eligible reviewers to form a panel")]
+    InsufficientReviewers,
+    #[msg("Signer is not a member of this review panel")]
+    NotOnPanel,
+    #[msg("Review bond has already been posted")]
+    BondAlreadyPosted,
+    #[msg("Review bond must be posted before voting")]
+    BondNotPosted,
+    #[msg("Reviewer has already voted on this claim")]
+    AlreadyVoted,
+    #[msg("The review period for this claim has ended")]
+    ReviewClosed,
+    #[msg("The review panel has not reached a decision yet")]
+    ReviewStillOpen,
+    #[msg("This panel seat has already been settled")]
+    AlreadySettled,
+    #[msg("Claimant does not hold a valid proof-of-coverage NFT")]
+    CoverageNotProven,
+    #[msg("Coverage has lapsed")]
+    CoverageLapsed,
+    #[msg("Coverage is still within its paid or grace period")]
+    CoverageStillActive,
+    #[msg("Claim amount exceeds the policy's per-claim coverage limit")]
+    CoverageLimitExceeded,
+    #[msg("Member has reached the policy's annual maximum for this benefit year")]
+    AnnualMaximumReached,
+    #[msg("Policy is not open for enrollment")]
+    PolicyNotOpen,
+    #[msg("Policy has been deactivated")]
+    PolicyInactive,
+    #[msg("Policy already has a newer version")]
+    PolicyAlreadyUpdated,
+    #[msg("The member's policy has been superseded; the next version must be supplied")]
+    PolicySuperseded,
+    #[msg("Policy changes require open enrollment or an attested life event")]
+    EnrollmentClosed,
+    #[msg("Membership already covers the maximum number of dependents")]
+    TooManyDependents,
+    #[msg("Member belongs to an employer group")]
+    InEmployerGroup,
+    #[msg("Employer group roster is full")]
+    EmployerGroupFull,
+    #[msg("Group premium is already paid for the next period")]
+    GroupPremiumNotDue,
+    #[msg("Premium is already paid for the next period")]
+    PremiumNotDue,
+    #[msg("Membership has been cancelled")]
+    MembershipCancelled,
+    #[msg("All dependents must be removed first")]
+    DependentsRemaining,
+    #[msg("The current premium period was paid by someone other than the member")]
+    PaidByThirdParty,
+    #[msg("Member has claims that are not yet paid or denied")]
+    ClaimsPending,
+    #[msg("Claim has already been appealed the maximum number of times")]
+    AppealLimitReached,
+    #[msg("Approved amount exceeds the amount claimed")]
+    ApprovedAmountExceedsClaim,
+    #[msg("Service dates are out of order or outside the period of coverage")]
+    InvalidServiceDates,
+    #[msg("Payout account does not belong to the claim's payee")]
+    InvalidPayee,
+    #[msg("Oracle report is older than the feed allows or than the current report")]
+    StaleOracleReport,
+    #[msg("Oracle report lacks signatures from enough of the feed's signers")]
+    OracleQuorumNotMet,
+    #[msg("Malformed Ed25519 signature instruction")]
+    InvalidOracleSignature,
+    #[msg("Fiat payout request is not in the required state")]
+    InvalidFiatPayoutStatus,
+    #[msg("Fiat payout request has expired")]
+    FiatPayoutExpired,
+    #[msg("Fiat payout request has not expired yet")]
+    FiatPayoutNotExpired,
+    #[msg("Stake is locked while registered as a reviewer")]
+    StakeLocked,
+    #[msg("Claim must carry metadata and its category's fee schedule address")]
+    FeeScheduleRequired,
+    #[msg("Proposal's execution window has closed")]
+    ProposalExpired,
+    #[msg("The slot seeding the review panel has not been produced yet")]
+    ReviewSeedPending,
+    #[msg("The slot seeding the review panel is too old; refresh the seed")]
+    ReviewSeedExpired,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::sysvar::instructions::{construct_instructions_data, BorrowedInstruction};
+    use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+
+    /// Deserializes an account from zeroed data, giving every field its zero
+    /// value.
+    fn zeroed<T: AnchorDeserialize>(len: usize) -> T {
+        T::deserialize(&mut &vec![0u8; len - 8][..]).unwrap()
+    }
+
+    /// A frozen token account holding `amount` of `mint` for `owner`.
+    fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> TokenAccount {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token::spl_token::state::{Account, AccountState};
+        let account = Account { mint, owner, amount, state: AccountState::Frozen, ..Account::default() };
+        let mut data = vec![0u8; Account::LEN];
+        account.pack_into_slice(&mut data);
+        TokenAccount::try_deserialize_unchecked(&mut &data[..]).unwrap()
+    }
+
+    /// A deterministic local keypair standing in for an oracle signer.
+    fn mock_signer(seed: u8) -> ed25519_dalek::Keypair {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[seed; 32]).unwrap();
+        let public = ed25519_dalek::PublicKey::from(&secret);
+        ed25519_dalek::Keypair { secret, public }
+    }
+
+    fn signer_key(keypair: &ed25519_dalek::Keypair) -> Pubkey {
+        Pubkey::new_from_array(keypair.public.to_bytes())
+    }
+
+    /// A feed whose signers are the mock signers for seeds 1 to 3, with a
+    /// threshold of two and a ten minute staleness limit.
+    fn mock_feed() -> OracleFeed {
+        let mut feed: OracleFeed = zeroed(OracleFeed::LEN);
+        for seed in 1..=3 {
+            feed.signers[seed as usize - 1] = signer_key(&mock_signer(seed));
+        }
+        feed.signer_count = 3;
+        feed.threshold = 2;
+        feed.max_staleness = 600;
+        feed
+    }
+
+    /// Counts the feed signers that signed `message` in a transaction
+    /// holding one Ed25519 instruction per entry of `signed`, each signed by
+    /// the mock signer with that seed over the paired message.
+    fn count_signed(feed: &OracleFeed, message: &[u8], signed: &[(u8, &[u8])]) -> u8 {
+        let ixs: Vec<_> = signed.iter().map(|(seed, msg)| new_ed25519_instruction(&mock_signer(*seed), msg)).collect();
+        let borrowed: Vec<_> = ixs
+            .iter()
+            .map(|ix| BorrowedInstruction { program_id: &ix.program_id, accounts: vec![], data: &ix.data })
+            .collect();
+        let mut data = construct_instructions_data(&borrowed);
+        let mut lamports = 0;
+        let owner = sysvar::ID;
+        let key = sysvar::instructions::ID;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        count_oracle_signatures(feed, &info, message).unwrap()
+    }
+
+    #[test]
+    fn oracle_signatures_count_distinct_feed_signers() {
+        let feed = mock_feed();
+        let message = OracleReport::message(&Pubkey::new_unique(), &Pubkey::new_unique(), 1, &[7; 32], 1_000);
+        assert_eq!(count_signed(&feed, &message, &[]), 0);
+        assert_eq!(count_signed(&feed, &message, &[(1, &message)]), 1);
+        assert_eq!(count_signed(&feed, &message, &[(1, &message), (3, &message)]), 2);
+        // The same signer twice counts once.
+        assert_eq!(count_signed(&feed, &message, &[(2, &message), (2, &message)]), 1);
+        // Signers outside the set and signatures over another message do not
+        // count.
+        let other = OracleReport::message(&Pubkey::new_unique(), &Pubkey::new_unique(), 2, &[7; 32], 1_000);
+        assert_eq!(count_signed(&feed, &message, &[(4, &message), (1, &other), (2, &message)]), 1);
+    }
+
+    #[test]
+    fn oracle_reports_need_threshold_freshness_and_novelty() {
+        let feed = mock_feed();
+        let now = 10_000;
+        assert!(feed.accept_report(now - 60, 0, 2, now).is_ok());
+        assert_eq!(feed.accept_report(now - 60, 0, 1, now).unwrap_err(), error!(ApolloError::OracleQuorumNotMet));
+        // Exactly `max_staleness` old is still fresh; one second more is not.
+        assert!(feed.accept_report(now - 600, 0, 3, now).is_ok());
+        assert_eq!(feed.accept_report(now - 601, 0, 3, now).unwrap_err(), error!(ApolloError::StaleOracleReport));
+        assert_eq!(feed.accept_report(now + 1, 0, 3, now).unwrap_err(), error!(ApolloError::InvalidParameter));
+        // Replaying the current report, or an older one, is rejected.
+        assert_eq!(feed.accept_report(now - 60, now - 60, 3, now).unwrap_err(), error!(ApolloError::StaleOracleReport));
+        assert_eq!(feed.accept_report(now - 120, now - 60, 3, now).unwrap_err(), error!(ApolloError::StaleOracleReport));
+    }
+
+    fn at(slot: u64, unix_timestamp: i64) -> Clock {
+        Clock { slot, unix_timestamp, ..Clock::default() }
+    }
+
+    #[test]
+    fn voting_power_ignores_the_snapshot_slot() {
+        let mut stake: Stake = zeroed(Stake::LEN);
+        stake.set_amount(100, &at(10, 1_000));
+        stake.set_amount(250, &at(20, 2_000));
+        assert_eq!(stake.voting_power_at(10), 0);
+        assert_eq!(stake.voting_power_at(11), 100);
+        assert_eq!(stake.voting_power_at(20), 100);
+        assert_eq!(stake.voting_power_at(21), 250);
+    }
+
+    #[test]
+    fn voting_power_keeps_the_last_update_in_a_slot() {
+        let mut stake: Stake = zeroed(Stake::LEN);
+        stake.set_amount(100, &at(10, 1_000));
+        stake.set_amount(400, &at(10, 1_000));
+        stake.set_amount(50, &at(10, 1_000));
+        assert_eq!(stake.checkpoint_count, 1);
+        assert_eq!(stake.voting_power_at(11), 50);
+    }
+
+    #[test]
+    fn voting_power_survives_ring_wrap() {
+        let mut stake: Stake = zeroed(Stake::LEN);
+        let writes = STAKE_CHECKPOINTS as u64 + 5;
+        for slot in 1..=writes {
+            stake.set_amount(slot * 10, &at(slot, slot as i64));
+        }
+        assert_eq!(stake.checkpoint_count, writes);
+        // Checkpoints for slots 1 to 5 have been overwritten, so a snapshot
+        // that needs one of them has an unknown balance.
+        assert_eq!(stake.voting_power_at(6), 0);
+        assert_eq!(stake.voting_power_at(7), 60);
+        assert_eq!(stake.voting_power_at(writes), (writes - 1) * 10);
+        assert_eq!(stake.voting_power_at(writes + 100), writes * 10);
+    }
+
+    #[test]
+    fn twab_averages_balance_over_the_window() {
+        let mut stake: Stake = zeroed(Stake::LEN);
+        stake.set_amount(100, &at(1, 1_000));
+        stake.set_amount(300, &at(2, 2_000));
+        assert_eq!(stake.cumulative_at(500), Some(0));
+        assert_eq!(stake.cumulative_at(1_500), Some(50_000));
+        assert_eq!(stake.cumulative_at(3_000), Some(400_000));
+        assert_eq!(stake.twab(1_000, 2_000), Some(100));
+        assert_eq!(stake.twab(1_000, 3_000), Some(200));
+        assert_eq!(stake.twab(0, 2_000), Some(50));
+        assert_eq!(stake.twab(2_000, 2_000), None);
+        assert_eq!(stake.twab(3_000, 2_000), None);
+    }
+
+    #[test]
+    fn twab_starts_at_the_oldest_retained_observation() {
+        let mut stake: Stake = zeroed(Stake::LEN);
+        let writes = TWAB_OBSERVATIONS as i64 + 3;
+        for t in 1..=writes {
+            stake.set_amount(100 * t as u64, &at(t as u64, t * 10));
+        }
+        // Observations at 10, 20 and 30 have been overwritten, so a window
+        // reaching back past 40 is measured from 40.
+        assert_eq!(stake.cumulative_at(35), None);
+        assert_eq!(stake.twab(0, 50), Some(400));
+        assert_eq!(stake.twab(35, 50), stake.twab(40, 50));
+        assert_eq!(stake.twab(0, 40), None);
+    }
+
+    #[test]
+    fn discount_tier_survives_a_wrapped_observation_ring() {
+        let mut cfg: Config = zeroed(Config::LEN);
+        cfg.discount_tiers = DiscountTier::defaults();
+        cfg.tier_twab_window = DEFAULT_TIER_TWAB_WINDOW;
+        let mut policy: Policy = zeroed(Policy::LEN);
+        policy.risk_premium = 1_000;
+        policy.admin_fee = 100;
+        // A long‑standing stake followed by many small top‑ups within the
+        // last day, which overwrite every observation before the window.
+        let aph = 1_000_000;
+        let mut stake: Stake = zeroed(Stake::LEN);
+        stake.set_amount(300_000 * aph, &at(1, 1));
+        let now = DEFAULT_TIER_TWAB_WINDOW * 2;
+        let first = now - 24 * 60 * 60;
+        for i in 1..=TWAB_OBSERVATIONS as u64 {
+            stake.set_amount((300_000 + i) * aph, &at(1 + i, first + i as i64 * 60));
+        }
+        let quote = quote_premium(&cfg, &policy, 0, Some(&stake), now);
+        assert_eq!(quote.tier, Some(DISCOUNT_TIERS as u8 - 1));
+        assert_eq!(quote.amount, 1_060);
+    }
+
+    /// A policy with a 500 deductible, 20 copay, 20% coinsurance and 1,500
+    /// out‑of‑pocket maximum in network, a costlier out‑of‑network tier and a
+    /// 10,000 annual maximum.
+    fn cost_sharing_policy() -> Policy {
+        let mut policy: Policy = zeroed(Policy::LEN);
+        policy.annual_max = 10_000;
+        policy.cost_sharing = CostSharing {
+            deductible: 500,
+            copay: 20,
+            coinsurance_bps: 2_000,
+            out_of_pocket_max: 1_500,
+            family_deductible: 800,
+        };
+        policy.out_of_network = CostSharing {
+            deductible: 1_000,
+            copay: 50,
+            coinsurance_bps: 4_000,
+            out_of_pocket_max: 3_000,
+            family_deductible: 2_000,
+        };
+        policy
+    }
+
+    #[test]
+    fn cost_sharing_applies_deductible_copay_coinsurance_and_caps() {
+        let policy = cost_sharing_policy();
+        let mut member: Member = zeroed(Member::LEN);
+        // All deductible.
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 300, true, None), 0);
+        // 200 deductible, 20 copay and 20% of 780.
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 1_000, true, None), 624);
+        assert_eq!(member.year_deductible, 500);
+        assert_eq!(member.year_out_of_pocket, 676);
+        // The member's 1,016 share is capped at the 824 left before the
+        // out‑of‑pocket maximum.
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 5_000, true, None), 4_176);
+        assert_eq!(member.year_out_of_pocket, 1_500);
+        // Past the out‑of‑pocket maximum the plan pays in full, up to the
+        // annual maximum.
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 6_000, true, None), 5_200);
+        assert_eq!(member.year_paid, 10_000);
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 100, true, None), 0);
+        // A new benefit year resets the accumulators.
+        assert_eq!(member.apply_cost_sharing(&policy, BENEFIT_YEAR + 100, 300, true, None), 0);
+        assert_eq!(member.year_paid, 0);
+        assert_eq!(member.year_out_of_pocket, 300);
+    }
+
+    #[test]
+    fn cost_sharing_tracks_dependents_against_the_family_deductible() {
+        let policy = cost_sharing_policy();
+        let mut member: Member = zeroed(Member::LEN);
+        let mut first: Dependent = zeroed(Dependent::LEN);
+        let mut second: Dependent = zeroed(Dependent::LEN);
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 500, true, None), 0);
+        // Only 300 of the family deductible is left.
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 1_000, true, Some(&mut first)), 544);
+        assert_eq!(first.year_deductible, 300);
+        assert_eq!(member.year_deductible, 500);
+        assert_eq!(member.family_year_deductible, 800);
+        // The family deductible is met, so the second dependent pays only
+        // the copay and coinsurance.
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 100, true, Some(&mut second)), 64);
+        assert_eq!(second.year_deductible, 0);
+        // A dependent's deductible from an earlier benefit year is ignored.
+        let now = BENEFIT_YEAR + 100;
+        assert_eq!(member.apply_cost_sharing(&policy, now, 200, true, Some(&mut first)), 0);
+        assert_eq!(first.benefit_year_start, BENEFIT_YEAR);
+        assert_eq!(first.year_deductible, 200);
+    }
+
+    #[test]
+    fn cost_sharing_uses_the_network_tier_with_shared_accumulators() {
+        let policy = cost_sharing_policy();
+        let mut member: Member = zeroed(Member::LEN);
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 1_000, false, None), 0);
+        // The out‑of‑network deductible also meets the in‑network one.
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 600, true, None), 464);
+        assert_eq!(member.year_out_of_pocket, 1_136);
+        // 50 copay and 40% of 1,950, within the out‑of‑network maximum even
+        // though it exceeds the in‑network one.
+        assert_eq!(member.apply_cost_sharing(&policy, 100, 2_000, false, None), 1_170);
+        assert_eq!(member.year_out_of_pocket, 1_966);
+    }
+
+    #[test]
+    fn proposals_pass_then_expire_after_the_execution_window() {
+        let mut proposal: Proposal = zeroed(Proposal::LEN);
+        proposal.voting_ends_at = 1_000;
+        proposal.quorum_votes = 100;
+        proposal.for_votes = 60;
+        proposal.against_votes = 50;
+        assert!(!proposal.has_passed(1_000));
+        assert!(proposal.has_passed(1_001));
+        assert!(!proposal.is_expired(1_000 + EXECUTION_WINDOW));
+        assert!(proposal.is_expired(1_001 + EXECUTION_WINDOW));
+        assert!(proposal.require_executable(1_001 + EXECUTION_WINDOW - 1).is_ok());
+        assert_eq!(proposal.require_executable(1_000).err(), Some(error!(ApolloError::ProposalNotPassed)));
+        assert_eq!(
+            proposal.require_executable(1_001 + EXECUTION_WINDOW).err(),
+            Some(error!(ApolloError::ProposalExpired))
+        );
+        proposal.status = ProposalStatus::Executed;
+        assert_eq!(proposal.require_executable(1_001).err(), Some(error!(ApolloError::InvalidProposalStatus)));
+        proposal.status = ProposalStatus::Active;
+        // Below quorum, or without a majority, the proposal fails.
+        proposal.for_votes = 40;
+        assert!(!proposal.has_passed(1_001));
+        proposal.for_votes = 50;
+        proposal.against_votes = 50;
+        assert!(!proposal.has_passed(1_001));
+    }
+
+    /// SlotHashes sysvar data holding `slots`, newest first.
+    fn slot_hashes_data(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn review_seed_is_the_first_produced_slot_from_the_commitment() {
+        // Slot 12 was skipped.
+        let mut data = slot_hashes_data(&[14, 13, 11, 10]);
+        let mut lamports = 0;
+        let key = sysvar::slot_hashes::ID;
+        let owner = sysvar::ID;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert_eq!(slot_hash_from(&info, 11).unwrap(), Some([11; 32]));
+        assert_eq!(slot_hash_from(&info, 12).unwrap(), Some([13; 32]));
+        assert_eq!(slot_hash_from(&info, 15).unwrap(), None);
+    }
+
+    #[test]
+    fn panel_draw_handles_weights_that_overflow_u64() {
+        let candidates: Vec<ReviewerEntry> = (0..PANEL_SIZE + 2)
+            .map(|_| ReviewerEntry { authority: Pubkey::new_unique(), weight: u64::MAX })
+            .collect();
+        let panel = draw_panel(candidates.clone(), &[3; 32], &Pubkey::new_unique());
+        for (i, reviewer) in panel.iter().enumerate() {
+            assert!(candidates.iter().any(|c| c.authority == *reviewer));
+            assert!(!panel[..i].contains(reviewer));
+        }
+    }
+
+    #[test]
+    fn late_panels_need_a_quorum_to_decide() {
+        let mut panel: ReviewPanel = zeroed(ReviewPanel::LEN);
+        panel.deadline = 1_000;
+        panel.votes[0] = Some(ReviewVote::Approve);
+        assert_eq!(panel.decide(500, 1_000).err(), Some(error!(ApolloError::ReviewStillOpen)));
+        // One vote after the deadline is not enough to pay the claim.
+        assert!(matches!(panel.decide(500, 1_001), Ok((ReviewOutcome::Escalated, 0))));
+        panel.votes[1] = Some(ReviewVote::Partial { amount: 200 });
+        panel.votes[2] = Some(ReviewVote::Deny);
+        assert!(matches!(panel.decide(500, 1_001), Ok((ReviewOutcome::Approved, 200))));
+        panel.votes[3] = Some(ReviewVote::Deny);
+        assert!(matches!(panel.decide(500, 1_001), Ok((ReviewOutcome::Escalated, 0))));
+        // A majority decides before the deadline.
+        panel.votes[4] = Some(ReviewVote::Deny);
+        assert!(matches!(panel.decide(500, 1_000), Ok((ReviewOutcome::Denied, 0))));
+    }
+
+    #[test]
+    fn review_bonds_scale_with_reputation() {
+        let mut reviewer: Reviewer = zeroed(Reviewer::LEN);
+        reviewer.reputation = INITIAL_REPUTATION;
+        assert_eq!(reviewer.bond_for(10_000, DEFAULT_REVIEW_BOND_BPS), 1_000);
+        reviewer.reputation = HIGH_REPUTATION;
+        assert_eq!(reviewer.bond_for(10_000, DEFAULT_REVIEW_BOND_BPS), 750);
+        reviewer.reputation = LOW_REPUTATION - 1;
+        assert_eq!(reviewer.bond_for(10_000, DEFAULT_REVIEW_BOND_BPS), 1_500);
+        // A bond that rounds down to nothing is still posted.
+        assert_eq!(reviewer.bond_for(5, DEFAULT_REVIEW_BOND_BPS), 1);
+    }
+
+    #[test]
+    fn the_bond_vault_closes_once_every_bonded_seat_settles() {
+        let mut panel: ReviewPanel = zeroed(ReviewPanel::LEN);
+        panel.bonds = [100, 0, 200, 0, 300];
+        assert!(!panel.settle_seat(0).unwrap());
+        // Seats without a bond do not hold the vault open.
+        assert!(!panel.settle_seat(1).unwrap());
+        assert!(!panel.settle_seat(2).unwrap());
+        assert!(panel.settle_seat(4).unwrap());
+        assert_eq!(panel.settle_seat(4).err(), Some(error!(ApolloError::AlreadySettled)));
+        // An unbonded seat settled after the vault closed still reports it
+        // as closable; `close_bond_vault` then finds no vault to close.
+        assert!(panel.settle_seat(3).unwrap());
+    }
+
+    #[test]
+    fn seat_verdicts_follow_the_panel_outcome() {
+        let mut panel: ReviewPanel = zeroed(ReviewPanel::LEN);
+        panel.votes = [
+            Some(ReviewVote::Approve),
+            Some(ReviewVote::Partial { amount: 10 }),
+            Some(ReviewVote::Deny),
+            None,
+            Some(ReviewVote::Approve),
+        ];
+        panel.outcome = ReviewOutcome::Approved;
+        // A partial approval sides with an approved outcome.
+        assert!(panel.verdict(0) == SeatVerdict::Consensus);
+        assert!(panel.verdict(1) == SeatVerdict::Consensus);
+        assert!(panel.verdict(2) == SeatVerdict::Dissent);
+        assert!(panel.verdict(3) == SeatVerdict::Missed);
+        panel.outcome = ReviewOutcome::Denied;
+        assert!(panel.verdict(1) == SeatVerdict::Dissent);
+        assert!(panel.verdict(2) == SeatVerdict::Consensus);
+        panel.outcome = ReviewOutcome::Escalated;
+        assert!(panel.verdict(0) == SeatVerdict::Unjudged);
+        assert!(panel.verdict(3) == SeatVerdict::Missed);
+    }
+
+    #[test]
+    fn verdicts_move_reputation_within_bounds() {
+        let mut reviewer: Reviewer = zeroed(Reviewer::LEN);
+        reviewer.reputation = MAX_REPUTATION - 1;
+        reviewer.record_verdict(SeatVerdict::Consensus);
+        assert_eq!((reviewer.reputation, reviewer.reviews_with_consensus), (MAX_REPUTATION, 1));
+        reviewer.record_verdict(SeatVerdict::Unjudged);
+        assert_eq!(reviewer.reputation, MAX_REPUTATION);
+        reviewer.reputation = REPUTATION_DISSENT_PENALTY - 1;
+        reviewer.record_verdict(SeatVerdict::Dissent);
+        assert_eq!((reviewer.reputation, reviewer.reviews_against_consensus), (0, 1));
+        reviewer.record_verdict(SeatVerdict::Missed);
+        assert_eq!((reviewer.reputation, reviewer.reviews_missed), (0, 1));
+    }
+
+    #[test]
+    fn bond_settlement_slashes_dissent_and_rewards_consensus() {
+        let mut cfg: Config = zeroed(Config::LEN);
+        cfg.review_slash_bps = DEFAULT_REVIEW_SLASH_BPS;
+        cfg.review_reward_bps = DEFAULT_REVIEW_REWARD_BPS;
+        cfg.review_fee_bps = DEFAULT_REVIEW_FEE_BPS;
+        cfg.protocol_fees = 1_000;
+        let settle = |cfg: &Config, verdict, reward_pool, premium_pool| {
+            let s = cfg.settle_bond(verdict, 1_000, 100_000, reward_pool, premium_pool);
+            (s.slashed, s.reward, s.fee_share)
+        };
+        assert_eq!(settle(&cfg, SeatVerdict::Consensus, 1_000, 1_000), (0, 100, 500));
+        assert_eq!(settle(&cfg, SeatVerdict::Dissent, 1_000, 1_000), (500, 0, 0));
+        assert_eq!(settle(&cfg, SeatVerdict::Missed, 1_000, 1_000), (500, 0, 0));
+        assert_eq!(settle(&cfg, SeatVerdict::Unjudged, 1_000, 1_000), (0, 0, 0));
+        // Rewards never exceed the reward pool, and the fee share never
+        // exceeds accrued protocol fees or the premium pool holding them.
+        assert_eq!(settle(&cfg, SeatVerdict::Consensus, 40, 1_000), (0, 40, 500));
+        cfg.protocol_fees = 300;
+        assert_eq!(settle(&cfg, SeatVerdict::Consensus, 1_000, 1_000), (0, 100, 300));
+        assert_eq!(settle(&cfg, SeatVerdict::Consensus, 1_000, 200), (0, 100, 200));
+    }
+
+    #[test]
+    fn fee_refunds_are_capped_at_protocol_fees() {
+        let mut cfg: Config = zeroed(Config::LEN);
+        cfg.protocol_fees = 100;
+        assert_eq!(cfg.apply_fee_delta(50), 50);
+        assert_eq!(cfg.protocol_fees, 150);
+        assert_eq!(cfg.apply_fee_delta(-40), -40);
+        assert_eq!(cfg.protocol_fees, 110);
+        // Reviewer payouts drew the fees down: only what is left is refunded.
+        assert_eq!(cfg.apply_fee_delta(-500), -110);
+        assert_eq!(cfg.protocol_fees, 0);
+    }
+
+    #[test]
+    fn the_authority_only_takes_claims_no_panel_can_review() {
+        let mut claim: Claim = zeroed(Claim::LEN);
+        let claimant = Pubkey::new_unique();
+        let program_id = crate::ID;
+        let mut registry: ReviewerRegistry = zeroed(ReviewerRegistry::LEN);
+        registry.reviewers =
+            (0..PANEL_SIZE).map(|_| ReviewerEntry { authority: Pubkey::new_unique(), weight: 1 }).collect();
+        let mut data = Vec::new();
+        registry.try_serialize(&mut data).unwrap();
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &program_id, false, 0);
+        claim.status = ClaimStatus::NeedsReview;
+        assert!(!claim.awaits_authority(&info, &claimant, &program_id).unwrap());
+        // The claimant cannot review their own claim, leaving too few.
+        let own = registry.reviewers[0].authority;
+        assert!(claim.awaits_authority(&info, &own, &program_id).unwrap());
+        claim.status = ClaimStatus::UnderReview;
+        assert!(!claim.awaits_authority(&info, &claimant, &program_id).unwrap());
+        claim.status = ClaimStatus::Escalated;
+        assert!(claim.awaits_authority(&info, &claimant, &program_id).unwrap());
+        // Without a registry no panel can be drawn.
+        let mut empty = Vec::new();
+        let mut lamports = 0;
+        let owner = Pubkey::default();
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut empty, &owner, false, 0);
+        claim.status = ClaimStatus::NeedsReview;
+        assert!(claim.awaits_authority(&info, &claimant, &program_id).unwrap());
+    }
+
+    #[test]
+    fn only_the_members_own_nft_proves_coverage() {
+        let mut member: Member = zeroed(Member::LEN);
+        member.coverage_mint = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        assert!(member.proves_coverage(&token_account(member.coverage_mint, holder, 1), &holder));
+        // A burned NFT, another member's NFT or someone else's wallet does
+        // not.
+        assert!(!member.proves_coverage(&token_account(member.coverage_mint, holder, 0), &holder));
+        assert!(!member.proves_coverage(&token_account(Pubkey::new_unique(), holder, 1), &holder));
+        assert!(!member.proves_coverage(&token_account(member.coverage_mint, holder, 1), &Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn coverage_lasts_through_the_grace_period() {
+        let mut policy: Policy = zeroed(Policy::LEN);
+        policy.grace_period = 100;
+        let mut member: Member = zeroed(Member::LEN);
+        member.active = true;
+        member.paid_through = 1_000;
+        assert!(member.is_covered(&policy, 1_100));
+        assert!(!member.is_covered(&policy, 1_101));
+        // Paying within the grace period extends from the missed due date;
+        // paying after it reinstates from the time of payment.
+        assert_eq!(member.next_period_start(&policy, 1_100), 1_000);
+        assert_eq!(member.next_period_start(&policy, 1_101), 1_101);
+        // A recorded lapse ends coverage even before the grace period does.
+        member.active = false;
+        assert!(!member.is_covered(&policy, 1_000));
+        assert_eq!(member.next_period_start(&policy, 1_000), 1_000);
+    }
+
+    #[test]
+    fn premiums_from_any_payer_are_credited_to_the_member() {
+        let mut member: Member = zeroed(Member::LEN);
+        member.authority = Pubkey::new_unique();
+        member.credit_premium(member.authority, 1_000, 100, PREMIUM_PERIOD);
+        let employer = Pubkey::new_unique();
+        member.credit_premium(employer, 800, 80, 2 * PREMIUM_PERIOD);
+        assert_eq!(member.paid_through, 2 * PREMIUM_PERIOD);
+        assert_eq!(member.total_premiums_paid, 1_800);
+        // Only the latest period is refundable, to whoever paid it.
+        assert_eq!((member.last_premium, member.last_premium_fee), (800, 80));
+        assert_eq!(member.last_premium_payer, employer);
+    }
+
+    #[test]
+    fn claims_are_held_to_the_coverage_limit_and_annual_maximum() {
+        let mut policy: Policy = zeroed(Policy::LEN);
+        policy.coverage_limit = 1_000;
+        policy.annual_max = 5_000;
+        let mut member: Member = zeroed(Member::LEN);
+        assert_eq!(member.admit_claim(&policy, 1_001, 0).err(), Some(error!(ApolloError::CoverageLimitExceeded)));
+        assert!(member.admit_claim(&policy, 1_000, 0).is_ok());
+        assert_eq!(member.year_claim_count, 1);
+        member.year_paid = 4_999;
+        assert!(member.admit_claim(&policy, 1_000, 0).is_ok());
+        member.year_paid = 5_000;
+        assert_eq!(member.admit_claim(&policy, 1, 0).err(), Some(error!(ApolloError::AnnualMaximumReached)));
+        assert_eq!(member.year_claim_count, 2);
+        // The maximum resets with the next benefit year.
+        assert!(member.admit_claim(&policy, 1_000, BENEFIT_YEAR).is_ok());
+        assert_eq!((member.year_paid, member.year_claim_count), (0, 1));
+    }
+
+    #[test]
+    fn policy_changes_prorate_the_premium_difference() {
+        let quote = |amount, fee| PremiumQuote { tier: None, discount: 0, fee, amount };
+        let mut member: Member = zeroed(Member::LEN);
+        member.credit_premium(Pubkey::new_unique(), 1_000, 100, PREMIUM_PERIOD);
+        // A quarter of the period left: a quarter of the difference is owed.
+        let now = PREMIUM_PERIOD * 3 / 4;
+        assert_eq!(member.reprice_period(&quote(1_000, 100), &quote(2_000, 300), now), (250, 50));
+        assert_eq!((member.last_premium, member.last_premium_fee), (2_000, 300));
+        assert_eq!(member.reprice_period(&quote(2_000, 300), &quote(600, 40), now), (-350, -65));
+        assert_eq!((member.last_premium, member.last_premium_fee), (600, 40));
+        // Nothing is owed either way once the period has run out.
+        assert_eq!(member.reprice_period(&quote(600, 40), &quote(1_000, 100), PREMIUM_PERIOD + 1), (0, 0));
+        assert_eq!((member.last_premium, member.last_premium_fee), (1_000, 100));
+    }
+
+    #[test]
+    fn only_self_paying_members_change_dependents() {
+        let mut member: Member = zeroed(Member::LEN);
+        member.authority = Pubkey::new_unique();
+        member.credit_premium(member.authority, 1_000, 100, PREMIUM_PERIOD);
+        assert!(member.check_dependent_change().is_ok());
+        member.credit_premium(Pubkey::new_unique(), 1_000, 100, 2 * PREMIUM_PERIOD);
+        assert_eq!(member.check_dependent_change().err(), Some(error!(ApolloError::PaidByThirdParty)));
+        member.credit_premium(member.authority, 1_000, 100, 3 * PREMIUM_PERIOD);
+        member.employer_group = Pubkey::new_unique();
+        assert_eq!(member.check_dependent_change().err(), Some(error!(ApolloError::InEmployerGroup)));
+    }
+
+    #[test]
+    fn dependent_claims_count_against_member_and_dependent() {
+        let mut member: Member = zeroed(Member::LEN);
+        let mut dependent: Dependent = zeroed(Dependent::LEN);
+        member.open_claim(None);
+        member.open_claim(Some(&mut dependent));
+        assert_eq!((member.open_claims, dependent.open_claims), (2, 1));
+        member.close_claim(Some(&mut dependent));
+        member.close_claim(None);
+        assert_eq!((member.open_claims, dependent.open_claims), (0, 0));
+    }
+
+    #[test]
+    fn group_premiums_split_between_sponsor_and_employee() {
+        let mut group: EmployerGroup = zeroed(EmployerGroup::LEN);
+        let quote = PremiumQuote { tier: None, discount: 0, fee: 101, amount: 1_001 };
+        let split = |group: &EmployerGroup| {
+            let shares = group.split(&quote);
+            (shares.sponsor_share, shares.sponsor_fee, shares.employee_share)
+        };
+        group.sponsor_share_bps = 7_500;
+        // Rounding favours the employee's share.
+        assert_eq!(split(&group), (750, 75, 251));
+        group.sponsor_share_bps = BPS_DENOMINATOR as u16;
+        assert_eq!(split(&group), (1_001, 101, 0));
+        group.sponsor_share_bps = 0;
+        assert_eq!(split(&group), (0, 0, 1_001));
+    }
+
+    #[test]
+    fn cancellation_refunds_the_unused_period_up_to_premiums_paid() {
+        let mut policy: Policy = zeroed(Policy::LEN);
+        policy.grace_period = 100;
+        let mut member: Member = zeroed(Member::LEN);
+        member.active = true;
+        member.credit_premium(member.authority, 1_000, 100, PREMIUM_PERIOD);
+        let refund = member.cancellation_refund(&policy, PREMIUM_PERIOD / 4);
+        assert_eq!((refund.last, refund.prior, refund.fees), (750, 0, 75));
+        // A period repriced above what was paid refunds no more than that.
+        member.last_premium = 4_000;
+        assert_eq!(member.cancellation_refund(&policy, PREMIUM_PERIOD / 4).last, 1_000);
+        // Nothing is unused once the period has run out or the lapse has
+        // been recorded.
+        assert_eq!(member.cancellation_refund(&policy, PREMIUM_PERIOD + 50).last, 0);
+        member.active = false;
+        assert_eq!(member.cancellation_refund(&policy, 0).last, 0);
+    }
+
+    #[test]
+    fn prepaid_periods_are_refunded_to_their_own_payers() {
+        let policy: Policy = zeroed(Policy::LEN);
+        let mut member: Member = zeroed(Member::LEN);
+        member.active = true;
+        member.authority = Pubkey::new_unique();
+        member.credit_premium(member.authority, 1_000, 100, PREMIUM_PERIOD);
+        // A family member prepays the next period halfway through the first.
+        let now = PREMIUM_PERIOD / 2;
+        assert!(member.premium_due(now));
+        let family = Pubkey::new_unique();
+        member.credit_premium(family, 1_200, 120, 2 * PREMIUM_PERIOD);
+        assert!(!member.premium_due(now));
+        assert!(member.premium_due(PREMIUM_PERIOD + 1));
+        assert_eq!(member.prior_premium_payer, member.authority);
+        assert_eq!(member.last_premium_payer, family);
+        // Cancelling a quarter into the first period refunds the prepaid
+        // period in full to the family member, and three quarters of the
+        // first to the subscriber, rather than 1.75 of the last period.
+        let refund = member.cancellation_refund(&policy, PREMIUM_PERIOD / 4);
+        assert_eq!((refund.last, refund.prior, refund.fees), (1_200, 750, 195));
+        // Once the first period is over only the prepaid one is unused.
+        let refund = member.cancellation_refund(&policy, PREMIUM_PERIOD * 3 / 2);
+        assert_eq!((refund.last, refund.prior, refund.fees), (600, 0, 60));
+    }
+
+    #[test]
+    fn claims_stay_with_the_enrollment_they_were_filed_under() {
+        let member = Pubkey::new_unique();
+        let mut claim: Claim = zeroed(Claim::LEN);
+        claim.member = member;
+        claim.enrollment_id = 3;
+        assert!(claim.belongs_to(&member, 3));
+        // The same wallet enrolling again reuses the Member address.
+        assert!(!claim.belongs_to(&member, 4));
+        assert!(!claim.belongs_to(&Pubkey::new_unique(), 3));
+    }
+
+    #[test]
+    fn denials_can_be_appealed_a_limited_number_of_times() {
+        let mut claim: Claim = zeroed(Claim::LEN);
+        let proposal = Pubkey::new_unique();
+        assert_eq!(claim.file_appeal([1; 32], proposal, 10).err(), Some(error!(ApolloError::InvalidClaimStatus)));
+        for appeal in 1..=MAX_APPEALS {
+            claim.status = ClaimStatus::Denied;
+            claim.file_appeal([appeal; 32], proposal, 10).unwrap();
+            assert!(claim.status == ClaimStatus::Appealed);
+            assert_eq!((claim.appeal_count, claim.appeal_hash), (appeal, [appeal; 32]));
+        }
+        claim.status = ClaimStatus::Denied;
+        assert_eq!(claim.file_appeal([9; 32], proposal, 10).err(), Some(error!(ApolloError::AppealLimitReached)));
+        assert!(claim.status == ClaimStatus::Denied);
+    }
+
+    #[test]
+    fn partial_approvals_record_their_reason() {
+        let mut claim: Claim = zeroed(Claim::LEN);
+        claim.amount = 1_000;
+        let (approved, reason) = claim.apply_fee_schedule(600, REASON_PANEL_ADJUSTED);
+        claim.record_approval(approved, 450, reason);
+        assert!(claim.status == ClaimStatus::PartiallyApproved);
+        assert_eq!((claim.approved_amount, claim.paid_amount, claim.member_responsibility), (600, 450, 150));
+        assert_eq!(claim.reason_code, REASON_PANEL_ADJUSTED);
+        // The fee schedule's cap takes over the reason when it applies.
+        claim.allowed_amount = Some(500);
+        let (approved, reason) = claim.apply_fee_schedule(600, REASON_PANEL_ADJUSTED);
+        claim.record_approval(approved, 500, reason);
+        assert_eq!((claim.approved_amount, claim.reason_code), (500, REASON_FEE_SCHEDULE));
+        // A full approval carries no reason code.
+        claim.allowed_amount = None;
+        let (approved, reason) = claim.apply_fee_schedule(1_000, REASON_PANEL_ADJUSTED);
+        claim.record_approval(approved, 1_000, reason);
+        assert!(claim.status == ClaimStatus::Paid);
+        assert_eq!((claim.member_responsibility, claim.reason_code), (0, REASON_NONE));
+    }
+
+    #[test]
+    fn service_dates_must_fall_within_coverage() {
+        let metadata = |service_start, service_end| ClaimMetadata {
+            claim_type: ClaimType::Medical,
+            service_start,
+            service_end,
+            procedure_category: 0,
+            diagnosis_category: 0,
+            codes_hash: [0; 32],
+            provider: None,
+        };
+        assert!(metadata(100, 100).validate(100, 100).is_ok());
+        assert!(metadata(150, 200).validate(100, 300).is_ok());
+        let invalid = Some(error!(ApolloError::InvalidServiceDates));
+        assert_eq!(metadata(200, 150).validate(100, 300).err(), invalid);
+        // Before coverage began, or not over yet.
+        assert_eq!(metadata(99, 150).validate(100, 300).err(), invalid);
+        assert_eq!(metadata(150, 301).validate(100, 300).err(), invalid);
+    }
+
+    #[test]
+    fn fiat_payouts_expire_after_their_claim_and_settlement_windows() {
+        let mut request: FiatPayoutRequest = zeroed(FiatPayoutRequest::LEN);
+        // Operators have `FIAT_CLAIM_WINDOW` from funding to take a request up.
+        request.status = FiatPayoutStatus::Pending;
+        assert_eq!(request.deadline(1_000).unwrap(), 1_000 + FIAT_CLAIM_WINDOW);
+        // Once taken up, settlement is due `FIAT_SETTLEMENT_WINDOW` later.
+        request.status = FiatPayoutStatus::Claimed;
+        request.claimed_at = 5_000;
+        assert_eq!(request.deadline(1_000).unwrap(), 5_000 + FIAT_SETTLEMENT_WINDOW);
+        for status in [FiatPayoutStatus::Settled, FiatPayoutStatus::Refunded] {
+            request.status = status;
+            assert_eq!(request.deadline(1_000).err(), Some(error!(ApolloError::InvalidFiatPayoutStatus)));
+        }
+    }
+
+    #[test]
+    fn premiums_move_members_to_the_version_in_effect() {
+        let first = Pubkey::new_unique();
+        let version = |version: u32, effective_at: i64, superseded_at: i64| {
+            let mut policy: Policy = zeroed(Policy::LEN);
+            policy.first_version = first;
+            policy.version = version;
+            policy.effective_at = effective_at;
+            policy.superseded_at = superseded_at;
+            policy
+        };
+        let v1 = version(1, 0, 100);
+        let v2 = version(2, 100, 200);
+        let v3 = version(3, 200, i64::MAX);
+        assert_eq!(policy_for_period(&&v1, None, 50).unwrap().version, 1);
+        assert_eq!(policy_for_period(&&v1, None, 150).err(), Some(error!(ApolloError::PolicySuperseded)));
+        assert_eq!(policy_for_period(&&v1, Some(&&v2), 150).unwrap().version, 2);
+        // Once v2 is superseded too, the member moves straight to v3.
+        assert_eq!(policy_for_period(&&v1, Some(&&v2), 250).err(), Some(error!(ApolloError::PolicySuperseded)));
+        assert_eq!(policy_for_period(&&v1, Some(&&v3), 250).unwrap().version, 3);
+        assert_eq!(policy_for_period(&&v1, Some(&&v3), 150).err(), Some(error!(ApolloError::PolicySuperseded)));
+        // Versions of another policy are rejected.
+        let mut other = version(3, 200, i64::MAX);
+        other.first_version = Pubkey::new_unique();
+        assert_eq!(policy_for_period(&&v1, Some(&&other), 250).err(), Some(error!(ApolloError::InvalidParameter)));
+    }
+
+    #[test]
+    fn policy_terms_need_out_of_network_cost_sharing() {
+        let policy = cost_sharing_policy();
+        let mut terms = PolicyTerms {
+            risk_premium: 100,
+            admin_fee: 10,
+            coverage_limit: 5_000,
+            annual_max: policy.annual_max,
+            grace_period: 0,
+            cost_sharing: policy.cost_sharing,
+            dependent_premium: 50,
+            out_of_network: CostSharing::default(),
+        };
+        assert_eq!(terms.validate().err(), Some(error!(ApolloError::InvalidParameter)));
+        terms.out_of_network = terms.cost_sharing;
+        assert!(terms.validate().is_ok());
+        terms.out_of_network = policy.out_of_network;
+        assert!(terms.validate().is_ok());
+    }
+
+    #[test]
+    fn unscheduled_claims_are_flagged_once_prices_are_tracked() {
+        let mut cfg: Config = zeroed(Config::LEN);
+        cfg.price_flag_percentile = 90;
+        let mut schedule: FeeSchedule = zeroed(FeeSchedule::LEN);
+        schedule.procedure_category = 7;
+        schedule.allowed_amount = 400;
+        schedule.billed_percentiles = [300, 500, 800, 1_000];
+        // No price oracle: nothing is checked.
+        assert_eq!(price_claim(&cfg, None, 100), (None, false));
+        cfg.price_oracle = Pubkey::new_unique();
+        // A category without a schedule no longer takes the fast lane.
+        assert_eq!(price_claim(&cfg, None, 100), (None, true));
+        assert_eq!(price_claim(&cfg, Some(&schedule), 800), (Some(400), false));
+        assert_eq!(price_claim(&cfg, Some(&schedule), 801), (Some(400), true));
+    }
 }